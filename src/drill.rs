@@ -0,0 +1,303 @@
+use std::io::{self, BufRead, Write};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use synthetic_language::latin::declension::*;
+use synthetic_language::normalize::{vowel_length_differences, VowelLength};
+use synthetic_language::{InflectionalCategorySet, Word};
+
+use crate::schedule::Scheduler;
+
+/// A noun in the drill's vocabulary together with the gender it is declined in.
+pub struct Entry<'a> {
+    pub word: Word<'a, NominalDeclension<'a>>,
+    pub gender: Gender,
+}
+
+impl<'a> Entry<'a> {
+    /// Every set of categories for which this entry actually has a form. Cells which the
+    /// declension leaves as `None` are never asked for.
+    pub fn askable_categories(&self) -> Vec<NominalCategories> {
+        NominalCategories::iter_through_variants()
+            .filter(|categories| categories.0 == self.gender)
+            .filter(|&categories| self.word.inflect(categories).is_ok())
+            .collect()
+    }
+}
+
+pub struct Question<'a> {
+    pub lemma: &'a str,
+    pub categories: NominalCategories,
+    /// Every accepted form, preferred first.
+    pub answers: Vec<String>,
+}
+
+impl<'a> Question<'a> {
+    /// Pick a random entry and a random form of it which exists.
+    pub fn random<R: Rng>(vocabulary: &'a [Entry], rng: &mut R) -> Option<Self> {
+        let askable = vocabulary
+            .iter()
+            .map(|entry| (entry, entry.askable_categories()))
+            .filter(|(_, categories)| !categories.is_empty())
+            .collect::<Vec<_>>();
+        let (entry, categories) = askable.choose(rng)?;
+        let categories = *categories.choose(rng)?;
+
+        Some(Question::new(entry, categories))
+    }
+
+    /// Pick the most overdue form in `scheduler`, or if none are due, a random form which is not
+    /// scheduled for later. If every form is scheduled for later, pick any random form.
+    pub fn next<R: Rng>(vocabulary: &'a [Entry], scheduler: &Scheduler, today: u64, rng: &mut R) -> Option<Self> {
+        for (lemma, index) in scheduler.due(today) {
+            let Some(entry) = vocabulary.iter().find(|entry| entry.word.lemma() == lemma) else {
+                continue;
+            };
+            let categories = NominalCategories::from_index(index)
+                .filter(|categories| entry.askable_categories().contains(categories));
+            if let Some(categories) = categories {
+                return Some(Question::new(entry, categories));
+            }
+        }
+
+        let unscheduled = vocabulary
+            .iter()
+            .flat_map(|entry| entry.askable_categories().into_iter().map(move |categories| (entry, categories)))
+            .filter(|(entry, categories)| scheduler.review(entry.word.lemma(), categories.index()).is_none())
+            .collect::<Vec<_>>();
+        match unscheduled.choose(rng) {
+            Some(&(entry, categories)) => Some(Question::new(entry, categories)),
+            None => Question::random(vocabulary, rng),
+        }
+    }
+
+    fn new(entry: &'a Entry, categories: NominalCategories) -> Self {
+        Question {
+            lemma: entry.word.lemma(),
+            categories,
+            answers: entry.word.inflect_all(categories),
+        }
+    }
+
+    pub fn prompt(&self) -> String {
+        let NominalCategories(gender, number, case) = self.categories;
+        format!("{} ({:?}) — {:?} {:?}: ", self.lemma, gender, case, number)
+    }
+
+    /// The preferred form.
+    pub fn answer(&self) -> &str {
+        &self.answers[0]
+    }
+
+    /// Grade `response` against every accepted form, ignoring how its macrons are encoded.
+    pub fn grade(&self, response: &str) -> Grade {
+        self.answers
+            .iter()
+            .filter_map(|answer| vowel_length_differences(response.trim(), answer))
+            .min_by_key(Vec::len)
+            .map_or(Grade::Wrong, |differences| {
+                if differences.is_empty() {
+                    Grade::Exact
+                } else {
+                    Grade::VowelLength(differences)
+                }
+            })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Grade {
+    Exact,
+    /// Correct except for the length of these vowels.
+    VowelLength(Vec<VowelLength>),
+    Wrong,
+}
+
+impl Grade {
+    /// The quality of the answer on the 0 to 5 scale used by `Review::record`.
+    pub fn quality(&self) -> u8 {
+        match self {
+            Grade::Exact => 5,
+            Grade::VowelLength(_) => 3,
+            Grade::Wrong => 1,
+        }
+    }
+}
+
+fn describe_vowel_lengths(differences: &[VowelLength]) -> String {
+    differences
+        .iter()
+        .map(|difference| {
+            format!(
+                "letter {} ({}) should be {}",
+                difference.index + 1,
+                difference.vowel,
+                if difference.long { "long" } else { "short" }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Score {
+    pub correct: u32,
+    /// Answers which were correct except for vowel length.
+    pub vowel_length: u32,
+    pub asked: u32,
+}
+
+/// Ask questions read from `input` until the player types `quit` or closes the input, and return
+/// the final score. Forms due for review in `scheduler` are asked first, and every answer is
+/// recorded in it.
+pub fn run<R: Rng>(
+    vocabulary: &[Entry],
+    scheduler: &mut Scheduler,
+    today: u64,
+    rng: &mut R,
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<Score> {
+    let mut score = Score::default();
+
+    writeln!(output, "Decline the word as asked. Type \"quit\" to stop.")?;
+    while let Some(question) = Question::next(vocabulary, scheduler, today, rng) {
+        write!(output, "{}", question.prompt())?;
+        output.flush()?;
+
+        let mut response = String::new();
+        if input.read_line(&mut response)? == 0 || response.trim() == "quit" {
+            break;
+        }
+
+        score.asked += 1;
+        let grade = question.grade(&response);
+        scheduler.record(question.lemma, question.categories.index(), grade.quality(), today);
+        match grade {
+            Grade::Exact => {
+                score.correct += 1;
+                writeln!(output, "Correct!")?;
+            }
+            Grade::VowelLength(differences) => {
+                score.vowel_length += 1;
+                writeln!(
+                    output,
+                    "Almost: {}; {}",
+                    question.answers.join(" or "),
+                    describe_vowel_lengths(&differences)
+                )?;
+            }
+            Grade::Wrong => writeln!(output, "Incorrect: {}", question.answers.join(" or "))?,
+        }
+    }
+    writeln!(
+        output,
+        "Score: {}/{} ({} more correct except for vowel length)",
+        score.correct, score.asked, score.vowel_length
+    )?;
+
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn vocabulary() -> Vec<Entry<'static>> {
+        vec![Entry {
+            word: Word::new(FOURTH_DECLENSION, "manus", "man"),
+            gender: Gender::Feminine,
+        }]
+    }
+
+    #[test]
+    fn test_skips_missing_forms() {
+        let categories = vocabulary()[0].askable_categories();
+
+        assert_eq!(categories.len(), 12);
+        assert!(categories.iter().all(|c| c.0 == Gender::Feminine && c.2 != Case::Locative));
+    }
+
+    #[test]
+    fn test_skips_defective_forms() {
+        let entry = Entry {
+            word: Word::builder(FIRST_DECLENSION, "dīvitiae", "dīviti")
+                .defective(NominalCategories::is_locative)
+                .defective(NominalCategories::is_singular)
+                .build()
+                .unwrap(),
+            gender: Gender::Feminine,
+        };
+        let categories = entry.askable_categories();
+
+        assert_eq!(categories.len(), 6);
+        assert!(categories.iter().all(|c| c.1 == Number::Plural && c.2 != Case::Locative));
+    }
+
+    #[test]
+    fn test_run_keeps_score() {
+        let vocabulary = vocabulary();
+        let mut scheduler = Scheduler::default();
+        let mut rng = StdRng::seed_from_u64(0);
+        let question = Question::next(&vocabulary, &scheduler, 0, &mut rng.clone()).unwrap();
+        let input = format!("{}\nwrong\nquit\n", question.answer());
+        let mut output = Vec::new();
+
+        let score = run(&vocabulary, &mut scheduler, 0, &mut rng, input.as_bytes(), &mut output).unwrap();
+
+        assert_eq!(score, Score { correct: 1, vowel_length: 0, asked: 2 });
+        assert_eq!(scheduler.review(question.lemma, question.categories.index()).unwrap().due, 1);
+    }
+
+    #[test]
+    fn test_due_forms_come_first() {
+        let vocabulary = vocabulary();
+        let mut scheduler = Scheduler::default();
+        let due = NominalCategories(Gender::Feminine, Number::Plural, Case::Genitive);
+        scheduler.record("manus", due.index(), 1, 10);
+        scheduler.record("manus", NominalCategories(Gender::Feminine, Number::Plural, Case::Dative).index(), 1, 9);
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let question = Question::next(&vocabulary, &scheduler, 11, &mut rng).unwrap();
+        assert_eq!(question.categories, NominalCategories(Gender::Feminine, Number::Plural, Case::Dative));
+
+        let question = Question::next(&vocabulary, &scheduler, 10, &mut rng).unwrap();
+        assert_eq!(question.categories, NominalCategories(Gender::Feminine, Number::Plural, Case::Dative));
+        assert_ne!(Question::next(&vocabulary, &scheduler, 5, &mut rng).unwrap().categories, due);
+    }
+
+    #[test]
+    fn test_accepts_every_variant() {
+        let question = Question {
+            lemma: "fīlius",
+            categories: NominalCategories(Gender::Masculine, Number::Singular, Case::Genitive),
+            answers: vec!["fīliī".to_string(), "fīlī".to_string()],
+        };
+
+        assert_eq!(question.grade("fīliī"), Grade::Exact);
+        assert_eq!(question.grade("fīlī\n"), Grade::Exact);
+        assert_eq!(question.grade("fīlius"), Grade::Wrong);
+    }
+
+    #[test]
+    fn test_grades_vowel_length() {
+        let question = Question {
+            lemma: "rosa",
+            categories: NominalCategories(Gender::Feminine, Number::Plural, Case::Genitive),
+            answers: vec!["rosārum".to_string()],
+        };
+
+        assert_eq!(question.grade("rosa\u{304}rum"), Grade::Exact);
+        assert_eq!(
+            question.grade("rosarum"),
+            Grade::VowelLength(vec![VowelLength { index: 3, vowel: 'a', long: true }])
+        );
+        assert_eq!(question.grade("rosīs"), Grade::Wrong);
+        assert_eq!(
+            describe_vowel_lengths(&[VowelLength { index: 3, vowel: 'a', long: true }]),
+            "letter 4 (a) should be long"
+        );
+    }
+}
@@ -1,5 +1,94 @@
+mod drill;
+mod parse;
+mod schedule;
+
+use std::{env, io};
+
 use synthetic_language::latin::declension::*;
+use synthetic_language::latin::lexicon::{Lexeme, Lexicon, LexiconEntry};
+use synthetic_language::Word;
+
+use drill::Entry;
+use schedule::Scheduler;
+
+/// Where review state is kept between drill sessions.
+const PROGRESS_FILE: &str = "latin_game_progress.tsv";
+
+/// The words the game knows, tagged by the chapter in which they are learned. None of the nouns
+/// has a locative, and "dīvitiae" has no singular.
+fn lexicon() -> Lexicon<'static> {
+    let noun = |declension, lemma, root, gender, gloss, chapter| {
+        let word = Word::builder(declension, lemma, root)
+            .defective(NominalCategories::is_locative)
+            .build()
+            .expect("the lexicon is well formed");
+        LexiconEntry::noun(word, gender).gloss(gloss).tag(chapter)
+    };
+
+    let mut lexicon = Lexicon::new();
+    for entry in [
+        noun(FIRST_DECLENSION, "poeta", "poet", Gender::Masculine, "poet", "chapter 1"),
+        noun(FIRST_DECLENSION, "rosa", "ros", Gender::Feminine, "rose", "chapter 1"),
+        noun(SECOND_DECLENSION, "dominus", "domin", Gender::Masculine, "master", "chapter 2"),
+        noun(SECOND_DECLENSION, "bellum", "bell", Gender::Neuter, "war", "chapter 2"),
+        noun(THIRD_DECLENSION, "canis", "can", Gender::Common, "dog", "chapter 3"),
+        noun(FOURTH_DECLENSION, "manus", "man", Gender::Feminine, "hand", "chapter 4"),
+        noun(FOURTH_DECLENSION, "cornū", "corn", Gender::Neuter, "horn", "chapter 4"),
+        noun(FIFTH_DECLENSION, "rēs", "r", Gender::Feminine, "thing", "chapter 5"),
+        LexiconEntry::noun(
+            Word::builder(FIRST_DECLENSION, "dīvitiae", "dīviti")
+                .defective(NominalCategories::is_locative)
+                .defective(NominalCategories::is_singular)
+                .build()
+                .expect("the lexicon is well formed"),
+            Gender::Feminine,
+        )
+        .gloss("riches")
+        .tag("chapter 1"),
+    ] {
+        lexicon.insert(entry);
+    }
+
+    lexicon
+}
+
+/// The nouns to drill: those tagged `tag`, or every noun in the lexicon.
+fn vocabulary(lexicon: &Lexicon<'static>, tag: Option<&str>) -> Vec<Entry<'static>> {
+    lexicon
+        .entries()
+        .map(|(_, entry)| entry)
+        .filter(|entry| tag.is_none_or(|tag| entry.tags().any(|t| t == tag)))
+        .filter_map(|entry| match entry.lexeme() {
+            Lexeme::Noun { word, gender } => Some(Entry { word: word.clone(), gender: *gender }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Run the drill, or with `parse`, the parsing game, optionally on the words tagged `--tag`, as in
+/// `latin_game parse --tag "chapter 1"`.
+fn main() -> io::Result<()> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let tag = args.iter().position(|arg| arg == "--tag").and_then(|i| args.get(i + 1));
+    let mode = args.first().filter(|arg| *arg != "--tag");
+
+    let vocabulary = vocabulary(&lexicon(), tag.map(String::as_str));
+    if vocabulary.is_empty() {
+        eprintln!("No nouns are tagged \"{}\"", tag.map_or("", String::as_str));
+        return Ok(());
+    }
+    let mut rng = rand::thread_rng();
+
+    match mode.map(String::as_str) {
+        Some("parse") => parse::run(&vocabulary, &mut rng, io::stdin().lock(), io::stdout())?,
+        _ => {
+            let mut scheduler = Scheduler::load(PROGRESS_FILE)?;
+            let today = schedule::today();
+            let score = drill::run(&vocabulary, &mut scheduler, today, &mut rng, io::stdin().lock(), io::stdout());
+            scheduler.save(PROGRESS_FILE)?;
+            score?
+        }
+    };
 
-fn main() {
-    println!("{}", FIRST_DECLENSION);
+    Ok(())
 }
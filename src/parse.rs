@@ -0,0 +1,179 @@
+use std::fmt::Debug;
+use std::io::{self, BufRead, Write};
+
+use rand::Rng;
+use synthetic_language::latin::declension::*;
+use synthetic_language::InflectionalCategory;
+
+use crate::drill::{Entry, Question, Score};
+
+/// Find the variant of `C` whose name starts with `token`, ignoring case. Returns `None` unless
+/// exactly one variant matches, so "abl" is accepted but "a" (ablative or accusative) is not.
+fn parse_variant<C: InflectionalCategory + Debug>(token: &str) -> Option<C> {
+    let token = token.to_lowercase();
+    let mut matches = C::iter_through_variants()
+        .filter(|variant| format!("{:?}", variant).to_lowercase().starts_with(&token));
+    let variant = matches.next()?;
+
+    matches.next().is_none().then_some(variant)
+}
+
+/// Read the player's analysis, e.g. "dative plural masculine" or "dat pl m", in any order. "n" is
+/// taken for neuter, as in dictionaries, rather than rejected as the start of nominative as well.
+pub fn parse_analysis(response: &str) -> Option<NominalCategories> {
+    let mut gender = None;
+    let mut number = None;
+    let mut case = None;
+
+    for token in response.split_whitespace() {
+        let token = match token.to_lowercase().as_str() {
+            "sg" => "singular",
+            "n" => "neuter",
+            _ => token,
+        };
+        let previous = match (
+            parse_variant::<Gender>(token),
+            parse_variant::<Number>(token),
+            parse_variant::<Case>(token),
+        ) {
+            (Some(g), None, None) => gender.replace(g).is_some(),
+            (None, Some(n), None) => number.replace(n).is_some(),
+            (None, None, Some(c)) => case.replace(c).is_some(),
+            _ => return None,
+        };
+        if previous {
+            return None;
+        }
+    }
+
+    Some(NominalCategories(gender?, number?, case?))
+}
+
+/// Every analysis of `form` as a form of `entry` in its gender.
+pub fn accepted_analyses(entry: &Entry, form: &str) -> Vec<NominalCategories> {
+    entry
+        .word
+        .analyze(form)
+        .into_iter()
+        .filter(|categories| categories.0 == entry.gender)
+        .collect()
+}
+
+/// Show forms read from `input` and ask for their case, number and gender until the player types
+/// `quit` or closes the input, and return the final score.
+pub fn run<R: Rng>(
+    vocabulary: &[Entry],
+    rng: &mut R,
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<Score> {
+    let mut score = Score::default();
+
+    writeln!(output, "Give the case, number and gender of each form. Type \"quit\" to stop.")?;
+    while let Some(question) = Question::random(vocabulary, rng) {
+        let entry = vocabulary
+            .iter()
+            .find(|entry| entry.word.lemma() == question.lemma)
+            .expect("questions are drawn from the vocabulary");
+        let accepted = accepted_analyses(entry, question.answer());
+
+        write!(output, "{} ({}): ", question.answer(), question.lemma)?;
+        output.flush()?;
+
+        let mut response = String::new();
+        if input.read_line(&mut response)? == 0 || response.trim() == "quit" {
+            break;
+        }
+
+        score.asked += 1;
+        match parse_analysis(&response) {
+            Some(analysis) if accepted.contains(&analysis) => {
+                score.correct += 1;
+                writeln!(output, "Correct!")?;
+            }
+            Some(analysis) => writeln!(output, "Incorrect: {}; it is {}", explain(entry, analysis), describe(&accepted))?,
+            None => writeln!(output, "Could not read that; it is {}", describe(&accepted))?,
+        }
+    }
+    writeln!(output, "Score: {}/{}", score.correct, score.asked)?;
+
+    Ok(score)
+}
+
+/// Why `analysis` is not an analysis of the form asked about: either it is the analysis of another
+/// form of `entry`, or `entry` has no form for it at all.
+fn explain(entry: &Entry, analysis: NominalCategories) -> String {
+    if analysis.0 != entry.gender {
+        return format!("\"{}\" is {:?}", entry.word.lemma(), entry.gender);
+    }
+
+    match entry.word.inflect(analysis) {
+        Ok(form) => format!("that would be {}", form),
+        Err(error) => error.to_string(),
+    }
+}
+
+fn describe(analyses: &[NominalCategories]) -> String {
+    analyses
+        .iter()
+        .map(|NominalCategories(gender, number, case)| format!("{:?} {:?} {:?}", case, number, gender))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synthetic_language::Word;
+
+    #[test]
+    fn test_parse_analysis() {
+        assert_eq!(
+            parse_analysis("dat pl m"),
+            Some(NominalCategories(Gender::Masculine, Number::Plural, Case::Dative))
+        );
+        assert_eq!(
+            parse_analysis("Feminine Ablative sg"),
+            Some(NominalCategories(Gender::Feminine, Number::Singular, Case::Ablative))
+        );
+        assert_eq!(
+            parse_analysis("nom sg n"),
+            Some(NominalCategories(Gender::Neuter, Number::Singular, Case::Nominative))
+        );
+        assert_eq!(
+            parse_analysis("dat pl n"),
+            Some(NominalCategories(Gender::Neuter, Number::Plural, Case::Dative))
+        );
+        assert_eq!(parse_analysis("a pl m"), None);
+        assert_eq!(parse_analysis("dat abl pl m"), None);
+        assert_eq!(parse_analysis("dat pl"), None);
+    }
+
+    #[test]
+    fn test_syncretic_forms_accept_every_analysis() {
+        let entry = Entry {
+            word: Word::new(FIRST_DECLENSION, "poeta", "poet"),
+            gender: Gender::Masculine,
+        };
+        let accepted = accepted_analyses(&entry, "poetīs");
+
+        assert!(accepted.contains(&parse_analysis("dative plural masculine").unwrap()));
+        assert!(accepted.contains(&parse_analysis("ablative plural masculine").unwrap()));
+        assert!(!accepted.contains(&parse_analysis("ablative plural feminine").unwrap()));
+    }
+
+    #[test]
+    fn test_explain() {
+        let entry = Entry {
+            word: Word::builder(FIRST_DECLENSION, "poeta", "poet").defective(NominalCategories::is_locative).build().unwrap(),
+            gender: Gender::Masculine,
+        };
+
+        assert_eq!(explain(&entry, parse_analysis("gen pl m").unwrap()), "that would be poetārum");
+        assert_eq!(explain(&entry, parse_analysis("gen pl f").unwrap()), "\"poeta\" is Masculine");
+        assert_eq!(
+            explain(&entry, parse_analysis("loc sg m").unwrap()),
+            "\"poeta\" is defective and has no such form"
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// An item to review: a lemma and the `InflectionalCategorySet::index` of one of its forms.
+pub type Item = (String, usize);
+
+/// The SM-2 review state of one item.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Review {
+    /// How many times in a row the item has been answered well enough.
+    pub repetitions: u32,
+    /// Days until the next review.
+    pub interval: u32,
+    pub ease: f64,
+    /// The day, counted from the UNIX epoch, on which the item is next due.
+    pub due: u64,
+}
+
+impl Default for Review {
+    fn default() -> Self {
+        Review { repetitions: 0, interval: 0, ease: 2.5, due: 0 }
+    }
+}
+
+impl Review {
+    /// Update the review after an answer of the given `quality`, from 0 (no idea) to 5 (perfect),
+    /// as in SM-2: answers of quality 3 or more lengthen the interval, anything less starts the
+    /// item over.
+    pub fn record(&mut self, quality: u8, today: u64) {
+        let quality = quality.min(5);
+
+        if quality >= 3 {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f64 * self.ease).round() as u32,
+            };
+            self.repetitions += 1;
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+
+        let lapse = (5 - quality) as f64;
+        self.ease = (self.ease + 0.1 - lapse * (0.08 + lapse * 0.02)).max(1.3);
+        self.due = today + self.interval as u64;
+    }
+}
+
+/// The review state of every item seen so far, kept between sessions in a tab separated file with
+/// one item per line.
+#[derive(Debug, Default, PartialEq)]
+pub struct Scheduler {
+    reviews: HashMap<Item, Review>,
+}
+
+impl Scheduler {
+    /// Load the scheduler saved at `path`, or an empty one if there is no such file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Scheduler::default()),
+            Err(error) => return Err(error),
+        };
+
+        let mut scheduler = Scheduler::default();
+        for (n, line) in contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("invalid review on line {}", n + 1));
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let [lemma, index, repetitions, interval, ease, due] = fields[..] else {
+                return Err(invalid());
+            };
+            let review = Review {
+                repetitions: repetitions.parse().map_err(|_| invalid())?,
+                interval: interval.parse().map_err(|_| invalid())?,
+                ease: ease.parse().map_err(|_| invalid())?,
+                due: due.parse().map_err(|_| invalid())?,
+            };
+            scheduler.reviews.insert((lemma.to_string(), index.parse().map_err(|_| invalid())?), review);
+        }
+
+        Ok(scheduler)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut items = self.reviews.iter().collect::<Vec<_>>();
+        items.sort_by(|a, b| a.0.cmp(b.0));
+
+        let contents = items
+            .into_iter()
+            .map(|((lemma, index), review)| {
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\n",
+                    lemma, index, review.repetitions, review.interval, review.ease, review.due
+                )
+            })
+            .collect::<String>();
+
+        fs::write(path, contents)
+    }
+
+    pub fn review(&self, lemma: &str, index: usize) -> Option<&Review> {
+        self.reviews.get(&(lemma.to_string(), index))
+    }
+
+    pub fn record(&mut self, lemma: &str, index: usize, quality: u8, today: u64) {
+        self.reviews
+            .entry((lemma.to_string(), index))
+            .or_default()
+            .record(quality, today);
+    }
+
+    /// Every item due on or before `today`, most overdue first.
+    pub fn due(&self, today: u64) -> Vec<(&str, usize)> {
+        let mut due = self
+            .reviews
+            .iter()
+            .filter(|(_, review)| review.due <= today)
+            .collect::<Vec<_>>();
+        due.sort_by(|a, b| a.1.due.cmp(&b.1.due).then_with(|| a.0.cmp(b.0)));
+
+        due.into_iter().map(|((lemma, index), _)| (lemma.as_str(), *index)).collect()
+    }
+}
+
+/// Today, counted in days from the UNIX epoch.
+pub fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / (24 * 60 * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intervals() {
+        let mut review = Review::default();
+
+        review.record(5, 100);
+        assert_eq!((review.interval, review.due), (1, 101));
+        review.record(5, 101);
+        assert_eq!((review.interval, review.due), (6, 107));
+        review.record(4, 107);
+        assert_eq!(review.interval, 16);
+        review.record(1, 124);
+        assert_eq!((review.repetitions, review.interval, review.due), (0, 1, 125));
+        assert!(review.ease >= 1.3);
+    }
+
+    #[test]
+    fn test_due_and_persistence() {
+        let mut scheduler = Scheduler::default();
+        scheduler.record("poeta", 36, 1, 10);
+        scheduler.record("rosa", 1, 5, 8);
+        scheduler.record("bellum", 50, 5, 10);
+        scheduler.record("bellum", 50, 5, 11);
+
+        assert_eq!(scheduler.due(11), vec![("rosa", 1), ("poeta", 36)]);
+
+        let path = std::env::temp_dir().join(format!("latin_game_schedule_{}.tsv", std::process::id()));
+        scheduler.save(&path).unwrap();
+        let loaded = Scheduler::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, scheduler);
+        assert_eq!(Scheduler::load(&path).unwrap(), Scheduler::default());
+    }
+}
@@ -1,7 +1,9 @@
 use proc_macro::{self, TokenStream};
 use quote::{quote, format_ident};
+use proc_macro2::Span;
 use syn::{parse_macro_input, ItemEnum, ItemStruct, Ident};
 use syn::parse::{Parse, ParseStream};
+use syn::parse::discouraged::Speculative;
 use iter_tools::Itertools;
 
 #[proc_macro_derive(InflectionalCategory)]
@@ -9,25 +11,54 @@ use iter_tools::Itertools;
 /// several variants.
 pub fn derive_inflectional_category(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ItemEnum);
+    if let Err(error) = check_category(&input) {
+        return error.to_compile_error().into();
+    }
     let name = &input.ident;
     let variants = input.variants;
     let variant_vec = variants
         .iter()
         .map(|v| &v.ident)
         .collect::<Vec<_>>();
-    let i = 0usize..variants.len();
+    let i = (0usize..variants.len()).collect::<Vec<_>>();
+    let count = variants.len();
 
     let gen = quote! {
+        impl #name {
+            /// Every variant, in order of `index`.
+            pub const ALL: [#name; #count] = [#(#name::#variant_vec),*];
+            pub const COUNT: usize = #count;
+
+            pub const fn index(&self) -> usize {
+                match self {
+                    #(#name::#variant_vec => #i),*
+                }
+            }
+        }
+
         impl InflectionalCategory for #name {
+            const NAME: &'static str = stringify!(#name);
+            const COUNT: usize = #count;
+
             fn index(&self) -> usize {
+                #name::index(self)
+            }
+
+            fn name(&self) -> &'static str {
                 match self {
-                    #(#name::#variant_vec => #i),*
+                    #(#name::#variant_vec => stringify!(#variant_vec)),*
+                }
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                match index {
+                    #(#i => Some(#name::#variant_vec),)*
+                    _ => None,
                 }
             }
 
             fn iter_through_variants() -> impl Iterator<Item = #name> {
-                let mut ret = vec![#(#name::#variant_vec),*];
-                ret.into_iter()
+                #name::ALL.into_iter()
             }
         }
     };
@@ -47,15 +78,40 @@ pub fn derive_inflectional_category_set(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ItemStruct);
     let name = &input.ident;
     let fields = input.fields;
-    let field_vec = fields.iter().map(|f| &f.ident);
+    let field_vec = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(i)),
+        })
+        .collect::<Vec<_>>();
+    let field_types = fields.iter().map(|f| &f.ty);
+    let field_types_for_count = fields.iter().map(|f| &f.ty);
+    let field_types_for_names = fields.iter().map(|f| &f.ty);
     let index_type = fields.iter().map(|_f| quote!{ usize });
+    let i = (0..fields.len()).map(syn::Index::from);
 
     let gen = quote! {
         impl InflectionalCategorySet for #name {
             type IndexType = (#(#index_type),*);
+            const NAME: &'static str = stringify!(#name);
+            const CATEGORY_NAMES: &'static [&'static str] = &[#(<#field_types_for_names as InflectionalCategory>::NAME),*];
+            const COUNT: usize = 1 #(* <#field_types_for_count as InflectionalCategory>::COUNT)*;
+
             fn index(&self) -> Self::IndexType {
                 (#(self.#field_vec.index()),*)
             }
+
+            fn names(&self) -> Vec<&'static str> {
+                vec![#(InflectionalCategory::name(&self.#field_vec)),*]
+            }
+
+            fn from_index(index: Self::IndexType) -> Option<Self> {
+                Some(#name {
+                    #(#field_vec: <#field_types as InflectionalCategory>::from_index(index.#i)?),*
+                })
+            }
         }
     };
 
@@ -74,37 +130,26 @@ pub fn derive_suffix_inflection(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ItemStruct);
     let name = &input.ident;
 
-    let underlying_type_attr = input
-        .attrs
-        .iter()
-        .find(|a| a.path.is_ident("suffix_inflection_over"))
-        .expect("suffix_inflection_over attribute specifying InflectionalCategorySet required to derive SuffixInflection");
-    let underlying_type = underlying_type_attr
-        .parse_args::<syn::Type>()
-        .expect("suffix_inflection_over attribute specifying InflectionalCategorySet required to derive SuffixInflection");
-
-    let n_categories_attr = input
-        .attrs
-        .iter()
-        .find(|a| a.path.is_ident("suffix_inflection_categories"))
-        .expect("suffix_inflection_categories attribute specifying number of attribute categories required to derive SuffixInflection");
-    let n_categories = n_categories_attr
-        .parse_args::<syn::LitInt>()
-        .expect("suffix_inflection_categories attribute specifying number of attribute categories required to derive SuffixInflection");
-    let n_categories = n_categories
-        .base10_parse::<usize>()
-        .expect("suffix_inflection_categories attribute specifying number of attribute categories required to derive SuffixInflection");
+    let underlying_type = match attribute_argument::<syn::Type>(&input, "suffix_inflection_over", "the InflectionalCategorySet") {
+        Ok(underlying_type) => underlying_type,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let n_categories = match attribute_argument::<syn::LitInt>(&input, "suffix_inflection_categories", "the number of categories")
+        .and_then(|n| n.base10_parse::<usize>())
+    {
+        Ok(n_categories) => n_categories,
+        Err(error) => return error.to_compile_error().into(),
+    };
     let n_categories = (0..n_categories).map(syn::Index::from);
 
-
     let gen = quote! {
         impl<'a> SuffixInflection<'a> for #name<'a> {
             type CategorySet = #underlying_type;
 
-            fn suffix(&self, categories: Self::CategorySet) -> Option<&'a str> {
+            fn suffixes(&self, categories: Self::CategorySet) -> impl Iterator<Item = Suffix<'a>> {
                 let index = categories.index();
 
-                self.suffixes #([index.#n_categories])*
+                self.suffixes #([index.#n_categories])* .iter().copied()
             }
         }
     };
@@ -112,6 +157,40 @@ pub fn derive_suffix_inflection(input: TokenStream) -> TokenStream {
     gen.into()
 }
 
+/// The argument of the attribute `#[name(...)]` on `input`, which is required and should specify
+/// `what`.
+fn attribute_argument<T: Parse>(input: &ItemStruct, name: &str, what: &str) -> syn::Result<T> {
+    let attr = input.attrs.iter().find(|a| a.path.is_ident(name)).ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            format!("a #[{}(...)] attribute specifying {} is required to derive SuffixInflection", name, what),
+        )
+    })?;
+
+    attr.parse_args::<T>().map_err(|error| {
+        syn::Error::new(error.span(), format!("{}; #[{}(...)] should specify {}", error, name, what))
+    })
+}
+
+/// Check that `category` can be an `InflectionalCategory`: it must have variants, none of which
+/// have fields.
+fn check_category(category: &ItemEnum) -> syn::Result<()> {
+    if category.variants.is_empty() {
+        return Err(syn::Error::new(
+            category.ident.span(),
+            format!("the inflectional category `{}` has no variants", category.ident),
+        ));
+    }
+
+    match category.variants.iter().find(|v| !matches!(v.fields, syn::Fields::Unit)) {
+        Some(variant) => Err(syn::Error::new_spanned(
+            &variant.fields,
+            format!("the variants of an inflectional category cannot have fields, but `{}` does", variant.ident),
+        )),
+        None => Ok(()),
+    }
+}
+
 struct SuffixInflectionOverCategoriesInput {
     suffix_inflection_struct_name: Ident,
     category_set_name: Ident,
@@ -126,8 +205,20 @@ impl Parse for SuffixInflectionOverCategoriesInput {
             categories : Vec::new(),
         };
 
-        while let Ok(item) = input.parse::<ItemEnum>() {
-            res.categories.push(item);
+        while !input.is_empty() {
+            if !input.peek(syn::Token![pub]) && !input.peek(syn::Token![enum]) && !input.peek(syn::Token![#]) {
+                return Err(input.error("expected an inflectional category such as `pub enum Number { Singular, Plural }`"));
+            }
+            let category = input.parse::<ItemEnum>()?;
+            check_category(&category)?;
+            res.categories.push(category);
+        }
+
+        if res.categories.is_empty() {
+            return Err(syn::Error::new(
+                res.category_set_name.span(),
+                format!("`{}` needs at least one inflectional category", res.category_set_name),
+            ));
         }
 
         Ok(res)
@@ -157,6 +248,15 @@ impl Parse for SuffixInflectionOverCategoriesInput {
 ///     /* ... */
 ///  }
 /// ```
+///
+/// Besides the tables written out with `suffixes!`, a `SuffixInflectionName` can be composed at
+/// compile time from others with the generated `const fn`s `with_name`, `with_cell`, and one
+/// `with_<category>` per category, which takes every cell of one variant from another table:
+/// ```compile_fail
+/// const ADJECTIVE: NominalDeclension = SECOND_DECLENSION
+///     .with_name("First/Second Adjective Declension")
+///     .with_gender(Gender::Feminine, &FIRST_DECLENSION);
+/// ```
 pub fn suffix_inflection_over_categories(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as SuffixInflectionOverCategoriesInput);
 
@@ -171,18 +271,18 @@ pub fn suffix_inflection_over_categories(input: TokenStream) -> TokenStream {
         .iter()
         .map(|c| &c.ident)
         .collect::<Vec<_>>();
-    let mut categories_sizes: Vec<usize> = input
+    let categories_sizes: Vec<usize> = input
         .categories
         .iter()
         .map(|c| c.variants.len())
         .collect();
-    categories_sizes.reverse();
     let mut categories_strides: Vec<usize> = categories_sizes
         .iter()
+        .rev()
         .fold(vec![1], |mut acc, &s| {acc.push(s * acc.last().unwrap()); acc});
     let total_n_elements = categories_strides.pop().unwrap();
     categories_strides.reverse();
-    let i = (0..input.categories.len()).map(syn::Index::from);
+    let i = (0..input.categories.len()).map(syn::Index::from).collect::<Vec<_>>();
 
     let categories_variants = input
         .categories
@@ -211,44 +311,161 @@ pub fn suffix_inflection_over_categories(input: TokenStream) -> TokenStream {
         .map(|_i| quote! { "{:12} " })
         .collect::<Vec<_>>();
 
+    let with_category_fns = categories_idents
+        .iter()
+        .map(|c| format_ident!("with_{}", snake_case(&c.to_string())))
+        .collect::<Vec<_>>();
+    let with_category_docs = categories_idents
+        .iter()
+        .map(|c| format!(" This inflection with every cell of the given `{}` taken from `from`.", c))
+        .collect::<Vec<_>>();
+
+    // With the `serde` feature, the categories and the category set are serializable, and so are the
+    // tables, which are read back as an owned `Table` rather than as a table borrowing its suffixes.
+    let (serde_derive, serde_impls) = if cfg!(feature = "serde") {
+        (
+            quote! { #[derive(::serde::Serialize, ::serde::Deserialize)] },
+            quote! {
+                /// Serialized as its name and a list of every set of categories with its suffixes, so that
+                /// the data does not depend on the order of the variants.
+                impl<'a> ::serde::Serialize for #suffix_inflection_struct_name<'a> {
+                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        use ::serde::ser::SerializeStruct;
+
+                        let suffixes = #category_set_name::ALL
+                            .iter()
+                            .map(|categories| (categories, self.suffixes[categories.index()]))
+                            .collect::<Vec<_>>();
+                        let mut table = serializer.serialize_struct(stringify!(#suffix_inflection_struct_name), 2)?;
+                        table.serialize_field("name", self.name)?;
+                        table.serialize_field("suffixes", &suffixes)?;
+                        table.end()
+                    }
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let layout_macro = layout_macro_name(category_set_name);
+    let layout_variants = input
+        .categories
+        .iter()
+        .map(|c| c.variants.iter().map(|v| &v.ident).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
     let gen = quote! {
         #(
             #[derive(Clone,Copy,Debug,PartialEq,InflectionalCategory)]
+            #serde_derive
             #categories
         )*
 
+        /// Pass the categories of the category set to `suffixes!`, so that tables over it can be
+        /// checked. Called by `suffixes! [CategorySetName => ...]`.
+        #[allow(unused_macros)]
+        macro_rules! #layout_macro {
+            ($($cells:tt)*) => {
+                suffixes! {
+                    @layout #category_set_name { #( #categories_idents { #( #layout_variants )* } )* }
+                    $($cells)*
+                }
+            };
+        }
+
         #[derive(Clone,Copy,Debug,PartialEq)]
-        pub struct #category_set_name (#(#categories_idents),*);
+        #serde_derive
+        pub struct #category_set_name (#(pub #categories_idents),*);
+
+        impl #category_set_name {
+            /// Every set of categories, in order of `index`.
+            pub const ALL: [#category_set_name; #total_n_elements] = [#(#category_set_name #all_categories_variants_tuples),*];
+            pub const COUNT: usize = #total_n_elements;
+
+            pub const fn index(&self) -> usize {
+                0 #(+ self.#i.index() * #categories_strides)*
+            }
+        }
 
         impl InflectionalCategorySet for #category_set_name {
             type IndexType = usize;
+            const NAME: &'static str = stringify!(#category_set_name);
+            const CATEGORY_NAMES: &'static [&'static str] = &[#(stringify!(#categories_idents)),*];
+            const COUNT: usize = #total_n_elements;
 
             fn index(&self) -> Self::IndexType {
-                0 #(+ self.#i.index() * #categories_strides)*
+                #category_set_name::index(self)
+            }
+
+            fn names(&self) -> Vec<&'static str> {
+                vec![#(InflectionalCategory::name(&self.#i)),*]
+            }
+
+            fn from_index(index: Self::IndexType) -> Option<Self> {
+                if index >= #total_n_elements {
+                    return None;
+                }
+
+                Some(#category_set_name (#(
+                    <#categories_idents as InflectionalCategory>::from_index(index / #categories_strides % #categories_sizes)?
+                ),*))
             }
 
             fn iter_through_variants() -> impl Iterator<Item = #category_set_name> {
-                let variants = vec![#(#category_set_name #all_categories_variants_tuples),*];
-                variants.into_iter()
+                #category_set_name::ALL.into_iter()
             }
         }
 
-        #[derive(Debug)]
+        #[derive(Clone,Copy,Debug,PartialEq)]
         pub struct #suffix_inflection_struct_name<'a> {
             name: &'a str,
-            suffixes: [Option<&'a str>; #total_n_elements],
+            suffixes: [&'a [Suffix<'a>]; #category_set_name::COUNT],
+        }
+
+        impl<'a> #suffix_inflection_struct_name<'a> {
+            pub fn name(&self) -> &'a str {
+                self.name
+            }
+
+            /// This inflection under another name.
+            pub const fn with_name(self, name: &'a str) -> Self {
+                #suffix_inflection_struct_name { name, ..self }
+            }
+
+            /// This inflection with the suffixes for `categories` replaced by `suffixes`.
+            pub const fn with_cell(mut self, categories: #category_set_name, suffixes: &'a [Suffix<'a>]) -> Self {
+                self.suffixes[categories.index()] = suffixes;
+                self
+            }
+
+            #(
+                #[doc = #with_category_docs]
+                pub const fn #with_category_fns(mut self, variant: #categories_idents, from: &Self) -> Self {
+                    let mut i = 0;
+                    while i < #total_n_elements {
+                        if i / #categories_strides % #categories_sizes == variant.index() {
+                            self.suffixes[i] = from.suffixes[i];
+                        }
+                        i += 1;
+                    }
+                    self
+                }
+            )*
         }
 
         impl<'a> SuffixInflection<'a> for #suffix_inflection_struct_name<'a> {
             type CategorySet = #category_set_name;
 
-            fn suffix(&self, categories: Self::CategorySet) -> Option<&'a str> {
+            fn suffixes(&self, categories: Self::CategorySet) -> impl Iterator<Item = Suffix<'a>> {
                 let index = categories.index();
 
-                self.suffixes[index]
+                self.suffixes[index].iter().copied()
             }
         }
 
+        #serde_impls
+
         impl<'a> ::std::fmt::Display for #suffix_inflection_struct_name<'a> {
             fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 write!(f, "{}\n======\n", self.name)?;
@@ -257,7 +474,9 @@ pub fn suffix_inflection_over_categories(input: TokenStream) -> TokenStream {
 
                 let mut previous_variant: Option<#category_set_name> = None;
                 for variant in variants {
-                    if let Some(suffix) = self.suffix(variant) {
+                    let suffixes = self.suffixes(variant).map(|s| s.to_string()).collect::<Vec<_>>();
+                    if !suffixes.is_empty() {
+                        let suffix = suffixes.join("/");
                         let #category_set_name (#( #current_vars ),*) = variant;
                         if previous_variant.is_some() {
                             let #category_set_name (#( #previous_vars ),*) = previous_variant.unwrap();
@@ -287,51 +506,477 @@ pub fn suffix_inflection_over_categories(input: TokenStream) -> TokenStream {
     gen.into()
 }
 
-struct Suffixes(Vec<Option<String>>);
+enum SuffixToken {
+    Ending(String),
+    Lemma,
+}
 
-impl Parse for Suffixes {
+/// A single cell of a suffix table: every accepted suffix, preferred first. A cell with no suffixes
+/// is a form which does not exist.
+struct SuffixCell {
+    suffixes: Vec<SuffixToken>,
+    span: Span,
+}
+
+impl Parse for SuffixCell {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut res = Vec::new();
+        let span = input.span();
 
-        while !input.is_empty() {
-            if let Ok(s) = input.parse::<syn::LitStr>() {
-                res.push(Some(s.value()));
-            } else if input.parse::<syn::token::Comma>().is_ok() {
-                continue;
+        loop {
+            if input.peek(syn::LitStr) {
+                res.push(SuffixToken::Ending(input.parse::<syn::LitStr>()?.value()));
             } else if let Ok(ident) = input.parse::<Ident>() {
                 if ident == "None" || ident == "N" {
-                    res.push(None);
+                    // Contributes nothing, so that a lone N is an empty cell.
+                } else if ident == "L" {
+                    res.push(SuffixToken::Lemma);
                 } else {
-                    res.push(Some(ident.to_string()));
+                    res.push(SuffixToken::Ending(ident.to_string()));
                 }
+            } else {
+                return Err(input.error("expected a suffix: a string literal, an identifier, N for no form or L for the lemma"));
+            }
+
+            if input.parse::<syn::token::Or>().is_err() {
+                break;
+            }
+        }
+
+        Ok(SuffixCell { suffixes: res, span })
+    }
+}
+
+/// A row of a suffix table, optionally labeled with the variants of the leading categories it
+/// covers, as in `Feminine Singular: "a" "ae" ...`.
+struct SuffixRow {
+    label: Option<Vec<Ident>>,
+    cells: Vec<SuffixCell>,
+}
+
+/// The categories of a category set and their variants, in order, as passed to `suffixes!` by the
+/// layout macro which `suffix_inflection_over_categories!` generates.
+struct Layout {
+    category_set_name: Ident,
+    categories: Vec<(Ident, Vec<Ident>)>,
+}
+
+impl Parse for Layout {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let category_set_name = input.parse()?;
+        let content;
+        syn::braced!(content in input);
+
+        let mut categories = Vec::new();
+        while !content.is_empty() {
+            let category = content.parse()?;
+            let variants;
+            syn::braced!(variants in content);
+            let mut names = Vec::new();
+            while !variants.is_empty() {
+                names.push(variants.parse()?);
             }
+            categories.push((category, names));
         }
 
-        Ok(Suffixes(res))
+        Ok(Layout { category_set_name, categories })
     }
 }
+
+/// Parse a row label: one or more identifiers followed by a single colon. Since bare identifiers
+/// are also cells, the label is taken to start at the first variant of the first category which
+/// leaves few enough identifiers for a label, or failing that, to be as long as the previous label.
+/// Returns `None` if the identifiers before the label are cells.
+fn parse_row_label(input: ParseStream, layout: &Layout, previous: Option<usize>) -> Option<Vec<Ident>> {
+    let fork = input.fork();
+    let mut run = Vec::new();
+
+    while let Ok(ident) = fork.parse::<Ident>() {
+        run.push(ident);
+    }
+    if run.is_empty() || fork.peek(syn::Token![::]) || fork.parse::<syn::Token![:]>().is_err() {
+        return None;
+    }
+
+    let max = layout.categories.len().saturating_sub(1).max(1);
+    let first_variants = &layout.categories.first()?.1;
+    let start = (0..run.len())
+        .find(|&i| first_variants.contains(&run[i]) && run.len() - i <= max)
+        .unwrap_or_else(|| run.len() - previous.unwrap_or(max).min(run.len()));
+    if start > 0 {
+        return None;
+    }
+
+    input.advance_to(&fork);
+    Some(run)
+}
+
+struct Suffixes {
+    layout: Option<Layout>,
+    rows: Vec<SuffixRow>,
+}
+
+impl Parse for Suffixes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let layout = if input.peek(syn::Token![@]) {
+            input.parse::<syn::Token![@]>()?;
+            let keyword = input.parse::<Ident>()?;
+            if keyword != "layout" {
+                return Err(syn::Error::new(keyword.span(), "expected `@layout`"));
+            }
+            Some(input.parse::<Layout>()?)
+        } else {
+            None
+        };
+
+        let mut rows: Vec<SuffixRow> = Vec::new();
+        while !input.is_empty() {
+            if input.parse::<syn::token::Comma>().is_ok() {
+                continue;
+            }
+            if let Some(layout) = &layout {
+                let previous = rows.last().and_then(|row| row.label.as_ref()).map(Vec::len);
+                if let Some(label) = parse_row_label(input, layout, previous) {
+                    rows.push(SuffixRow { label: Some(label), cells: Vec::new() });
+                    continue;
+                }
+            }
+
+            let cell = input.parse::<SuffixCell>()?;
+            match rows.last_mut() {
+                Some(row) => row.cells.push(cell),
+                None => rows.push(SuffixRow { label: None, cells: vec![cell] }),
+            }
+        }
+
+        if rows.len() > 1 && rows[0].label.is_none() {
+            return Err(syn::Error::new(
+                rows[0].cells[0].span,
+                "every row must be labeled if any is; expected a label such as `Feminine Singular:`",
+            ));
+        }
+
+        Ok(Suffixes { layout, rows })
+    }
+}
+
+impl Layout {
+    fn sizes(&self) -> Vec<usize> {
+        self.categories.iter().map(|(_, variants)| variants.len()).collect()
+    }
+
+    /// The number of cells covered by one variant of each category, i.e. the product of the sizes
+    /// of the categories after it.
+    fn strides(&self) -> Vec<usize> {
+        let sizes = self.sizes();
+
+        (0..sizes.len()).map(|i| sizes[i + 1..].iter().product()).collect()
+    }
+
+    fn count(&self) -> usize {
+        self.sizes().iter().product()
+    }
+
+    /// The variants of the first `n` categories of the cell at `position`, e.g. "Feminine Plural".
+    fn describe(&self, position: usize, n: usize) -> String {
+        let strides = self.strides();
+
+        self.categories[..n]
+            .iter()
+            .zip(strides)
+            .map(|((_, variants), stride)| variants[position / stride % variants.len()].to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The categories after the first `n`, e.g. "Case" or "Number and Person".
+    fn columns(&self, n: usize) -> String {
+        self.categories[n..]
+            .iter()
+            .map(|(category, _)| category.to_string())
+            .collect::<Vec<_>>()
+            .join(" and ")
+    }
+
+    /// Check that `rows` have one cell for every set of categories, in order, and point at the row
+    /// and column where they do not.
+    fn check(&self, rows: &[SuffixRow]) -> syn::Result<()> {
+        match rows {
+            [SuffixRow { label: None, cells }] => self.check_unlabeled(cells),
+            _ => self.check_labeled(rows),
+        }
+    }
+
+    fn check_labeled(&self, rows: &[SuffixRow]) -> syn::Result<()> {
+        let strides = self.strides();
+        let mut errors = Vec::new();
+        let mut position = 0;
+
+        for row in rows {
+            let label = row.label.as_ref().expect("labeled tables only have labeled rows");
+            if label.len() >= self.categories.len() {
+                errors.push(syn::Error::new(
+                    label[0].span(),
+                    format!("a row label names at most {} categories", self.categories.len() - 1),
+                ));
+                continue;
+            }
+
+            let mut valid = true;
+            for (ident, (category, variants)) in label.iter().zip(&self.categories) {
+                if !variants.contains(ident) {
+                    let variants = variants.iter().map(Ident::to_string).collect::<Vec<_>>().join(", ");
+                    errors.push(syn::Error::new(
+                        ident.span(),
+                        format!("`{}` is not a variant of `{}`; expected one of {}", ident, category, variants),
+                    ));
+                    valid = false;
+                }
+            }
+
+            let row_length = strides[label.len() - 1];
+            let expected = self.describe(position, label.len());
+            let found = label.iter().map(Ident::to_string).collect::<Vec<_>>().join(" ");
+            if valid && (position % row_length != 0 || found != expected) {
+                errors.push(syn::Error::new(
+                    label[0].span(),
+                    format!("expected the row `{}` here, found `{}`", expected, found),
+                ));
+            }
+
+            if row.cells.len() > row_length {
+                errors.push(syn::Error::new(
+                    row.cells[row_length].span,
+                    format!(
+                        "row `{}` has {} cells but needs {}, one per {}; this cell is one too many",
+                        found, row.cells.len(), row_length, self.columns(label.len())
+                    ),
+                ));
+            } else if row.cells.len() < row_length {
+                errors.push(syn::Error::new(
+                    label[0].span(),
+                    format!(
+                        "row `{}` has {} cells but needs {}, one per {}",
+                        found, row.cells.len(), row_length, self.columns(label.len())
+                    ),
+                ));
+            }
+
+            position += row_length;
+        }
+
+        if errors.is_empty() && position < self.count() {
+            errors.push(syn::Error::new(
+                last_span(rows),
+                format!(
+                    "`{}` needs {} cells but the rows cover {}; the row `{}` and those after it are missing",
+                    self.category_set_name, self.count(), position, self.describe(position, self.categories.len() - 1)
+                ),
+            ));
+        } else if errors.is_empty() && position > self.count() {
+            errors.push(syn::Error::new(
+                last_span(rows),
+                format!("`{}` needs {} cells but the rows cover {}", self.category_set_name, self.count(), position),
+            ));
+        }
+
+        combine(errors)
+    }
+
+    fn check_unlabeled(&self, cells: &[SuffixCell]) -> syn::Result<()> {
+        let count = self.count();
+        let errors = self.check_lines(cells);
+        if !errors.is_empty() {
+            return combine(errors);
+        }
+
+        if cells.len() > count {
+            Err(syn::Error::new(
+                cells[count].span,
+                format!(
+                    "`{}` has only {} sets of categories, but this is cell {}",
+                    self.category_set_name, count, count + 1
+                ),
+            ))
+        } else if cells.len() < count {
+            Err(syn::Error::new(
+                cells.last().map_or_else(Span::call_site, |cell| cell.span),
+                format!(
+                    "expected {} cells, one per `{}`, but found {}; the cell for `{}` is the first missing",
+                    count, self.category_set_name, cells.len(), self.describe(cells.len(), self.categories.len())
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Treat each line of an unlabeled table as a row, and check that every row has as many cells
+    /// as most of them do. Nothing is checked if the table is on one line, if source locations are
+    /// unavailable, or if the usual row does not cover whole variants of the leading categories.
+    fn check_lines(&self, cells: &[SuffixCell]) -> Vec<syn::Error> {
+        let mut lines: Vec<&[SuffixCell]> = Vec::new();
+        let mut start = 0;
+        for i in 1..=cells.len() {
+            if i == cells.len() || cells[i].span.start().line != cells[start].span.start().line {
+                lines.push(&cells[start..i]);
+                start = i;
+            }
+        }
+        if lines.len() < 2 {
+            return Vec::new();
+        }
+
+        let mut lengths = lines.iter().map(|line| line.len()).collect::<Vec<_>>();
+        lengths.sort_unstable();
+        let row_length = lengths
+            .iter()
+            .copied()
+            .max_by_key(|&length| lengths.iter().filter(|&&l| l == length).count())
+            .expect("there are at least two lines");
+        let Some(n) = self.strides().iter().position(|&stride| stride == row_length).map(|i| i + 1) else {
+            return Vec::new();
+        };
+
+        let mut errors = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let row = self.describe(i * row_length, n);
+            if line.len() > row_length {
+                errors.push(syn::Error::new(
+                    line[row_length].span,
+                    format!(
+                        "row {} (`{}`) has {} cells but needs {}, one per {}; this cell is one too many",
+                        i + 1, row, line.len(), row_length, self.columns(n)
+                    ),
+                ));
+            } else if line.len() < row_length {
+                errors.push(syn::Error::new(
+                    line[0].span,
+                    format!(
+                        "row {} (`{}`) has {} cells but needs {}, one per {}",
+                        i + 1, row, line.len(), row_length, self.columns(n)
+                    ),
+                ));
+            }
+        }
+
+        errors
+    }
+}
+
+/// The span of the last cell or label of a table, where missing rows are reported.
+fn last_span(rows: &[SuffixRow]) -> Span {
+    rows.last()
+        .and_then(|row| {
+            row.cells
+                .last()
+                .map(|cell| cell.span)
+                .or_else(|| row.label.as_ref().map(|label| label[0].span()))
+        })
+        .unwrap_or_else(Span::call_site)
+}
+
+fn combine(errors: Vec<syn::Error>) -> syn::Result<()> {
+    let mut errors = errors.into_iter();
+    match errors.next() {
+        Some(mut error) => {
+            error.extend(errors);
+            Err(error)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Either a table of suffixes, or a table to be checked against a category set, in which case the
+/// category set's layout macro is called to fill in its categories.
+enum SuffixesInput {
+    Table(Suffixes),
+    OverCategorySet { category_set_name: Ident, rest: proc_macro2::TokenStream },
+}
+
+impl Parse for SuffixesInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(syn::Token![=>]) {
+            let category_set_name = input.parse()?;
+            input.parse::<syn::Token![=>]>()?;
+            Ok(SuffixesInput::OverCategorySet { category_set_name, rest: input.parse()? })
+        } else {
+            Ok(SuffixesInput::Table(input.parse()?))
+        }
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+/// The name of the layout macro generated for a category set, e.g. `nominal_categories_layout`.
+fn layout_macro_name(category_set_name: &Ident) -> Ident {
+    format_ident!("{}_layout", snake_case(&category_set_name.to_string()), span = category_set_name.span())
+}
+
 #[proc_macro]
-/// This will generate a vector suffixes of the type Option<&'a str> with less line noise in the
-/// input than normal.
+/// This will generate an array of suffix cells of the type &'a [Suffix<'a>] with less line noise in
+/// the input than normal.
 /// Example:
 /// ```no_compile
-/// const suffixes: [Option<&'static str>; 6] = suffixes! [
-///     "a" "ae" ae "am" a N
+/// const suffixes: [&'static [Suffix<'static>]; 7] = suffixes! [
+///     "a" "ae" ae "am" "ā"|"a" N L
 /// ];
 /// ```
-/// N or None represents a None. All other identifiers are turned into strings, as in the second
-/// ae above. You may also use string literals (probably the preferable route).
+/// N or None represents a form which does not exist and L represents `Suffix::Lemma`, a form which
+/// is the lemma as-is. Alternative suffixes for the same cell are separated by `|`, preferred
+/// first. All other identifiers are turned into strings, as in the second ae above. You may also
+/// use string literals (probably the preferable route).
+///
+/// If the table starts with the name of a category set generated by
+/// `suffix_inflection_over_categories!` in the same module, it is checked against that set: it must
+/// have exactly one cell per set of categories, and a row with too many or too few cells is pointed
+/// out. Rows may then also be labeled with the variants of the leading categories they cover, which
+/// are checked against the variants' names and order:
+/// ```no_compile
+/// suffixes! [NominalCategories =>
+///     Feminine Singular: "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
+///     Feminine Plural:   "ae" "ārum" "īs" "ās" "īs" "ae" "īs"
+///     /* ... */
+/// ]
+/// ```
 pub fn suffixes(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as Suffixes);
-    let suffixes = input.0;
-    let suffixes = suffixes
+    let input = parse_macro_input!(input as SuffixesInput);
+    let input = match input {
+        SuffixesInput::OverCategorySet { category_set_name, rest } => {
+            let layout_macro = layout_macro_name(&category_set_name);
+            return quote! { #layout_macro! { #rest } }.into();
+        }
+        SuffixesInput::Table(input) => input,
+    };
+
+    if let Some(layout) = &input.layout {
+        if let Err(error) = layout.check(&input.rows) {
+            // A block, so that several errors are still an expression.
+            let error = error.to_compile_error();
+            return quote! { { #error unreachable!() } }.into();
+        }
+    }
+
+    let suffixes = input
+        .rows
         .into_iter()
-        .map(|s| {
-            if let Some(s) = s {
-                quote! { Some(#s) }
-            } else {
-                quote! { None }
-            }
+        .flat_map(|row| row.cells)
+        .map(|cell| {
+            let suffixes = cell.suffixes.into_iter().map(|s| match s {
+                SuffixToken::Ending(s) => quote! { Suffix::Ending(#s) },
+                SuffixToken::Lemma => quote! { Suffix::Lemma },
+            });
+            quote! { &[#(#suffixes),*] }
         });
     let gen = quote! {
         [#(#suffixes),*]
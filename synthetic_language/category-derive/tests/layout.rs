@@ -0,0 +1,7 @@
+/// The checks of a `suffixes!` table against its category set: the number of cells, the length of
+/// each row, and the order and names of labeled rows.
+#[test]
+fn layout() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/layout/*.rs");
+}
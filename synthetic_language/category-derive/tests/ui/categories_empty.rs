@@ -0,0 +1,8 @@
+use category_derive::suffix_inflection_over_categories;
+
+suffix_inflection_over_categories! {
+    Declension
+    Categories
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use category_derive::suffix_inflection_over_categories;
+
+suffix_inflection_over_categories! {
+    Declension
+    Categories
+
+    pub enum Number {
+        Singular,
+        Plural,
+    }
+
+    42
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use category_derive::InflectionalCategory;
+
+#[derive(InflectionalCategory)]
+enum Number {
+    Singular,
+    Plural(u8),
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use category_derive::{suffix_inflection_over_categories, suffixes, InflectionalCategory};
+use synthetic_language::*;
+
+suffix_inflection_over_categories! {
+    Declension
+    Categories
+
+    pub enum Number {
+        Singular,
+        Plural,
+    }
+
+    pub enum Case {
+        Nominative,
+        Genitive,
+        Dative,
+    }
+}
+
+const SUFFIXES: [&[Suffix]; Categories::COUNT] = suffixes![Categories =>
+    Plural:   "ae" "ārum" "īs"
+    Singular: "a" "ae" "ae"
+];
+
+fn main() {}
@@ -0,0 +1,10 @@
+use category_derive::SuffixInflection;
+
+#[derive(SuffixInflection)]
+#[suffix_inflection_over(Categories)]
+#[suffix_inflection_categories(two)]
+struct Table<'a> {
+    suffixes: [[&'a str; 2]; 2],
+}
+
+fn main() {}
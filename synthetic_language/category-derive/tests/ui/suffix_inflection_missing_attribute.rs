@@ -0,0 +1,9 @@
+use category_derive::SuffixInflection;
+
+#[derive(SuffixInflection)]
+#[suffix_inflection_categories(2)]
+struct Table<'a> {
+    suffixes: [[&'a str; 2]; 2],
+}
+
+fn main() {}
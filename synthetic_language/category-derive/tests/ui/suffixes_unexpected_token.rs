@@ -0,0 +1,6 @@
+use category_derive::suffixes;
+use synthetic_language::Suffix;
+
+const SUFFIXES: [&[Suffix]; 3] = suffixes!["a" 1 "ae"];
+
+fn main() {}
@@ -0,0 +1,6 @@
+use category_derive::suffixes;
+use synthetic_language::Suffix;
+
+const SUFFIXES: [&[Suffix]; 2] = suffixes![Categories => "a" "ae"];
+
+fn main() {}
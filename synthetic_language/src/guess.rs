@@ -0,0 +1,307 @@
+//! Guessing the analyses of a form which is not in any lexicon, by stripping every suffix of a
+//! set of tables from its end, and the paradigm of a word from a handful of its forms.
+
+use crate::normalize::{nfc, strip_macrons};
+use crate::*;
+
+/// One way `form` could be built: `root` followed by `suffix`, the suffix of `table` for
+/// `categories`.
+#[derive(Debug)]
+pub struct Hypothesis<'t, 's, T: SuffixInflection<'s>> {
+    pub root: String,
+    pub table: &'t T,
+    pub categories: T::CategorySet,
+    pub suffix: &'s str,
+    /// Whether the suffix is the preferred one for `categories`, rather than a variant.
+    pub preferred: bool,
+    /// Whether the form's macrons agree with the suffix's. A form written without macrons, such
+    /// as "rosarum", still matches suffixes with them.
+    pub macrons_match: bool,
+}
+
+impl<'t, 's, T: SuffixInflection<'s>> Hypothesis<'t, 's, T> {
+    /// The preferred form for `categories` of a word of `table` with this root, as "rosa" for the
+    /// nominative singular of "rosārum". This is `None` if the table has no such form, or if the
+    /// form is the lemma, which cannot be known from the root.
+    pub fn predict(&self, categories: T::CategorySet) -> Option<String> {
+        match self.table.suffix(categories) {
+            Ok(Suffix::Ending(ending)) => Some(format!("{}{}", self.root, ending)),
+            Ok(Suffix::Lemma) | Err(_) => None,
+        }
+    }
+}
+
+/// Every way `form` could be a non-empty root followed by a suffix of one of `tables`, most
+/// plausible first: longer suffixes before shorter ones, since they say more about the form and
+/// leave less of it unexplained; then those whose macrons agree with the form's; then preferred
+/// suffixes before variants; and otherwise in the order of `tables` and of their categories.
+/// Forms which are the lemma itself, such as the nominative "rēx", are not guessed.
+pub fn guess<'t, 's, T>(form: &str, tables: &'t [T]) -> Vec<Hypothesis<'t, 's, T>>
+where
+    T: SuffixInflection<'s>,
+    T::CategorySet: Clone,
+{
+    let form = nfc(form);
+    let stripped_form = strip_macrons(&form);
+
+    let mut hypotheses = Vec::new();
+    for table in tables {
+        for categories in T::CategorySet::iter_through_variants() {
+            for (i, suffix) in table.suffixes(categories.clone()).enumerate() {
+                let Suffix::Ending(ending) = suffix else {
+                    continue;
+                };
+                let Some((root, macrons_match)) = strip_ending(&form, &stripped_form, ending) else {
+                    continue;
+                };
+
+                hypotheses.push(Hypothesis {
+                    root,
+                    table,
+                    categories: categories.clone(),
+                    suffix: ending,
+                    preferred: i == 0,
+                    macrons_match,
+                });
+            }
+        }
+    }
+
+    // The sort is stable, so ties stay in the order of the tables and their categories.
+    hypotheses.sort_by_key(|hypothesis| {
+        (
+            std::cmp::Reverse(hypothesis.suffix.chars().count()),
+            !hypothesis.macrons_match,
+            !hypothesis.preferred,
+        )
+    });
+    hypotheses
+}
+
+/// The non-empty root left by removing `ending` from `form`, whose macrons are `stripped_form`, and
+/// whether the macrons agree. Where they do not, the root is still cut from the form as written,
+/// keeping its own macrons, as "rēg" of "rēgīs" with the ending "is".
+fn strip_ending(form: &str, stripped_form: &str, ending: &str) -> Option<(String, bool)> {
+    let (root, macrons_match) = match form.strip_suffix(ending) {
+        Some(root) => (root.to_string(), true),
+        None => {
+            let stripped_ending = strip_macrons(ending);
+            if !stripped_form.ends_with(&stripped_ending) {
+                return None;
+            }
+            // Stripping macrons keeps every letter, so the lengths in characters agree.
+            let length = stripped_form.chars().count() - stripped_ending.chars().count();
+            (form.chars().take(length).collect(), false)
+        }
+    };
+
+    (!root.is_empty()).then_some((root, macrons_match))
+}
+
+/// An observed form which an inferred paradigm accepts, but not as the form it predicts: a variant
+/// such as the accusative "turrem" beside "turrim", or a form whose macrons differ from the
+/// prediction's, such as "rosarum". `predicted` is every form accepted, preferred first.
+#[derive(Debug, PartialEq)]
+pub struct Conflict<C> {
+    pub form: String,
+    pub categories: C,
+    pub predicted: Vec<String>,
+}
+
+/// A table and root which account for every observed form of a word.
+#[derive(Debug)]
+pub struct Inference<'t, 's, T: SuffixInflection<'s>> {
+    pub table: &'t T,
+    pub root: String,
+    /// The lemma, if it was observed in a cell where the table gives the lemma itself, as "rēx" in
+    /// the third declension.
+    pub lemma: Option<String>,
+    /// Every form the word is predicted to have, preferred forms only. Forms which are the lemma
+    /// are left out if the lemma was not observed.
+    pub paradigm: Vec<(T::CategorySet, String)>,
+    /// Every observation which is not the predicted form as written.
+    pub conflicts: Vec<Conflict<T::CategorySet>>,
+}
+
+/// Every table of `tables` and root which agree with all of `observations`, those with the fewest
+/// conflicts first, and otherwise in the order of `tables`. As in `guess`, an observation agrees
+/// with a form which differs from it only in its macrons, so "rosarum" is of the first declension;
+/// it is then flagged as a conflict, as is one which is only a variant of the predicted form.
+pub fn infer<'t, 's, T>(observations: &[(&str, T::CategorySet)], tables: &'t [T]) -> Vec<Inference<'t, 's, T>>
+where
+    T: SuffixInflection<'s> + Clone,
+    T::CategorySet: Clone,
+{
+    let observations = observations
+        .iter()
+        .map(|(form, categories)| (nfc(form), categories.clone()))
+        .collect::<Vec<_>>();
+
+    let mut candidates: Vec<(&'t T, String)> = Vec::new();
+    for table in tables {
+        for (form, categories) in &observations {
+            let stripped_form = strip_macrons(form);
+            for suffix in table.suffixes(categories.clone()) {
+                let Suffix::Ending(ending) = suffix else {
+                    continue;
+                };
+                let Some((root, _)) = strip_ending(form, &stripped_form, ending) else {
+                    continue;
+                };
+                // Roots which differ only in their macrons are the same candidate; the one with
+                // macrons is kept, so that the paradigm has them.
+                let same = candidates
+                    .iter_mut()
+                    .find(|(t, r)| std::ptr::eq(*t, table) && strip_macrons(r) == strip_macrons(&root));
+                match same {
+                    Some((_, r)) if *r == strip_macrons(r) => *r = root,
+                    Some(_) => {}
+                    None => candidates.push((table, root)),
+                }
+            }
+        }
+    }
+
+    let mut inferences = candidates
+        .into_iter()
+        .filter_map(|(table, root)| infer_paradigm(table, root, &observations))
+        .collect::<Vec<_>>();
+    // The sort is stable, so ties stay in the order of the tables.
+    inferences.sort_by_key(|inference| inference.conflicts.len());
+    inferences
+}
+
+/// The paradigm of a word of `table` with this root, if it agrees with every observation.
+fn infer_paradigm<'t, 's, T>(
+    table: &'t T,
+    root: String,
+    observations: &[(String, T::CategorySet)],
+) -> Option<Inference<'t, 's, T>>
+where
+    T: SuffixInflection<'s> + Clone,
+    T::CategorySet: Clone,
+{
+    let is_lemma = |categories: &T::CategorySet| table.suffix(categories.clone()) == Ok(Suffix::Lemma);
+    let lemma = observations
+        .iter()
+        .find(|(_, categories)| is_lemma(categories))
+        .map(|(form, _)| form.clone());
+    let word = Word::new(table.clone(), lemma.clone().unwrap_or_default(), root.clone());
+
+    let mut conflicts = Vec::new();
+    for (form, categories) in observations {
+        let predicted = word.inflect_all(categories.clone());
+        if !predicted.iter().any(|predicted| strip_macrons(predicted) == strip_macrons(form)) {
+            return None;
+        }
+        if predicted.first() != Some(form) {
+            conflicts.push(Conflict { form: form.clone(), categories: categories.clone(), predicted });
+        }
+    }
+    let paradigm = T::CategorySet::iter_through_variants()
+        .filter(|categories| lemma.is_some() || !is_lemma(categories))
+        .filter_map(|categories| Some((categories.clone(), word.inflect(categories).ok()?)))
+        .collect();
+
+    Some(Inference { table, root, lemma, paradigm, conflicts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latin::conjugation::{self, Aspect, Mood, Person, Tense, VerbCategories, Voice, CONJUGATIONS, FIRST_CONJUGATION};
+    use crate::latin::declension::*;
+
+    #[test]
+    fn test_guess_nouns() {
+        let hypotheses = guess("rosārum", &DECLENSIONS);
+        let best = &hypotheses[0];
+
+        assert_eq!(best.root, "ros");
+        assert_eq!(best.table.name(), "First Declension");
+        assert_eq!(best.categories, NominalCategories(Gender::Feminine, Number::Plural, Case::Genitive));
+        assert_eq!(best.predict(NominalCategories(Gender::Feminine, Number::Singular, Case::Nominative)).as_deref(), Some("rosa"));
+        assert!(hypotheses.iter().any(|hypothesis| hypothesis.root == "rosār" && hypothesis.table.name() == "Third Declension"));
+
+        let best = &guess("rosarum", &DECLENSIONS)[0];
+        assert_eq!((best.root.as_str(), best.table.name(), best.macrons_match), ("ros", "First Declension", false));
+
+        let best = &guess("rēgis", &DECLENSIONS)[0];
+        assert_eq!(best.root, "rēg");
+        assert_eq!(best.predict(NominalCategories(Gender::Masculine, Number::Singular, Case::Nominative)), None);
+
+        let hypotheses = guess("rēgīs", &DECLENSIONS);
+        assert!(hypotheses.iter().any(|hypothesis| hypothesis.suffix == "is" && !hypothesis.macrons_match && hypothesis.root == "rēg"));
+        assert!(hypotheses.iter().all(|hypothesis| hypothesis.root.starts_with("rēg")));
+
+        assert!(guess("ā", &DECLENSIONS).is_empty());
+    }
+
+    #[test]
+    fn test_guess_verbs() {
+        let best = &guess("amābat", &CONJUGATIONS)[0];
+
+        assert_eq!(best.root, "am");
+        assert_eq!(best.table.name(), FIRST_CONJUGATION.name());
+        assert_eq!(
+            best.categories,
+            VerbCategories(Voice::Active, Mood::Indicative, Aspect::Imperfective, Tense::Past, conjugation::Number::Singular, Person::Third)
+        );
+    }
+
+    #[test]
+    fn test_infer() {
+        let feminine = |number, case| NominalCategories(Gender::Feminine, number, case);
+        let inferences = infer(&[("rosārum", feminine(Number::Plural, Case::Genitive)), ("rosīs", feminine(Number::Plural, Case::Dative))], &DECLENSIONS);
+        let best = &inferences[0];
+
+        assert_eq!((best.table.name(), best.root.as_str()), ("First Declension", "ros"));
+        assert!(best.conflicts.is_empty());
+        assert!(best.paradigm.contains(&(feminine(Number::Singular, Case::Nominative), "rosa".to_string())));
+        assert!(!best.paradigm.iter().any(|(categories, _)| categories.0 == Gender::Neuter));
+
+        let masculine = |number, case| NominalCategories(Gender::Masculine, number, case);
+        let observations = [
+            ("rēgis", masculine(Number::Singular, Case::Genitive)),
+            ("rēx", masculine(Number::Singular, Case::Nominative)),
+            ("rēgibus", masculine(Number::Plural, Case::Dative)),
+        ];
+        let best = &infer(&observations, &DECLENSIONS)[0];
+        assert_eq!((best.table.name(), best.root.as_str(), best.lemma.as_deref()), ("Third Declension", "rēg", Some("rēx")));
+        assert!(best.conflicts.is_empty());
+        assert!(best.paradigm.contains(&(masculine(Number::Singular, Case::Vocative), "rēx".to_string())));
+    }
+
+    #[test]
+    fn test_infer_conflicts() {
+        let feminine = |number, case| NominalCategories(Gender::Feminine, number, case);
+        let observations = [("rosarum", feminine(Number::Plural, Case::Genitive)), ("rosīs", feminine(Number::Plural, Case::Dative))];
+        let best = &infer(&observations, &DECLENSIONS)[0];
+
+        assert_eq!((best.table.name(), best.root.as_str()), ("First Declension", "ros"));
+        assert_eq!(
+            best.conflicts,
+            [Conflict {
+                form: "rosarum".to_string(),
+                categories: feminine(Number::Plural, Case::Genitive),
+                predicted: vec!["rosārum".to_string()],
+            }]
+        );
+
+        let masculine = |number, case| NominalCategories(Gender::Masculine, number, case);
+        let observations = [("rēgis", masculine(Number::Singular, Case::Genitive)), ("rēgīs", masculine(Number::Plural, Case::Accusative))];
+        let inferences = infer(&observations, &DECLENSIONS);
+        assert_eq!((inferences[0].table.name(), inferences[0].conflicts.len()), ("Third Declension (I-Stem)", 0));
+        let mixed = inferences.iter().find(|inference| inference.table.name() == "Third Declension (Mixed I-Stem)").unwrap();
+        assert_eq!(mixed.conflicts[0].predicted, ["rēgēs", "rēgīs"]);
+        assert!(!inferences.iter().any(|inference| inference.table.name() == "Third Declension"));
+
+        // Without the genitive singular, "puella, puellōris" of the third declension would fit.
+        let observations = [
+            ("puella", feminine(Number::Singular, Case::Nominative)),
+            ("puellae", feminine(Number::Singular, Case::Genitive)),
+            ("puellōrum", feminine(Number::Plural, Case::Genitive)),
+        ];
+        assert!(infer(&observations, &DECLENSIONS).is_empty());
+    }
+}
@@ -1,21 +1,10 @@
+use category_derive::{suffix_inflection_over_categories, suffixes};
 use crate::*;
-use category_derive::suffix_inflection_over_categories;
 
 suffix_inflection_over_categories! {
     VerbConjugation
     VerbCategories
 
-    pub enum Person {
-        First,
-        Second,
-        Third,
-    }
-
-    pub enum Number {
-        Singular,
-        Plural,
-    }
-
     pub enum Voice {
         Active,
         Passive,
@@ -27,4 +16,462 @@ suffix_inflection_over_categories! {
         Imperative,
         Participle,
     }
+
+    pub enum Aspect {
+        Imperfective,
+        Perfective,
+    }
+
+    pub enum Tense {
+        Present,
+        Past,
+        Future,
+    }
+
+    pub enum Number {
+        Singular,
+        Plural,
+    }
+
+    pub enum Person {
+        First,
+        Second,
+        Third,
+    }
+}
+
+// The conjugation tables below give one row per voice, mood, aspect and tense, and one column per
+// number and person. The imperfective past is the imperfect and the perfective tenses are the
+// perfect, pluperfect and future perfect. Participles, which decline like adjectives, are given in
+// the masculine nominative singular in the third person singular column.
+//
+// The conjugation tables only give the present system. The perfective tenses, the perfect passive
+// participle and the future active participle are built on the perfect and supine stems instead of
+// the present root, so they are left as `None` and given by `PERFECT_SYSTEM` and `SUPINE_SYSTEM`,
+// which are the same for every conjugation. See `latin::verb::Verb`.
+
+pub const FIRST_CONJUGATION: VerbConjugation = VerbConjugation {
+    name: "First Conjugation",
+    suffixes: suffixes! [VerbCategories =>
+        Active Indicative Imperfective Present:   "ō"    "ās"     "at"     "āmus"   "ātis"    "ant"
+        Active Indicative Imperfective Past:      "ābam" "ābās"   "ābat"   "ābāmus" "ābātis"  "ābant"
+        Active Indicative Imperfective Future:    "ābō"  "ābis"   "ābit"   "ābimus" "ābitis"  "ābunt"
+        Active Indicative Perfective Present:     N      N        N        N        N         N
+        Active Indicative Perfective Past:        N      N        N        N        N         N
+        Active Indicative Perfective Future:      N      N        N        N        N         N
+        Active Subjunctive Imperfective Present:  "em"   "ēs"     "et"     "ēmus"   "ētis"    "ent"
+        Active Subjunctive Imperfective Past:     "ārem" "ārēs"   "āret"   "ārēmus" "ārētis"  "ārent"
+        Active Subjunctive Imperfective Future:   N      N        N        N        N         N
+        Active Subjunctive Perfective Present:    N      N        N        N        N         N
+        Active Subjunctive Perfective Past:       N      N        N        N        N         N
+        Active Subjunctive Perfective Future:     N      N        N        N        N         N
+        Active Imperative Imperfective Present:   N      "ā"      N        N        "āte"     N
+        Active Imperative Imperfective Past:      N      N        N        N        N         N
+        Active Imperative Imperfective Future:    N      "ātō"    "ātō"    N        "ātōte"   "antō"
+        Active Imperative Perfective Present:     N      N        N        N        N         N
+        Active Imperative Perfective Past:        N      N        N        N        N         N
+        Active Imperative Perfective Future:      N      N        N        N        N         N
+        Active Participle Imperfective Present:   N      N        "āns"    N        N         N
+        Active Participle Imperfective Past:      N      N        N        N        N         N
+        Active Participle Imperfective Future:    N      N        N        N        N         N
+        Active Participle Perfective Present:     N      N        N        N        N         N
+        Active Participle Perfective Past:        N      N        N        N        N         N
+        Active Participle Perfective Future:      N      N        N        N        N         N
+        Passive Indicative Imperfective Present:  "or"   "āris"   "ātur"   "āmur"   "āminī"   "antur"
+        Passive Indicative Imperfective Past:     "ābar" "ābāris" "ābātur" "ābāmur" "ābāminī" "ābantur"
+        Passive Indicative Imperfective Future:   "ābor" "āberis" "ābitur" "ābimur" "ābiminī" "ābuntur"
+        Passive Indicative Perfective Present:    N      N        N        N        N         N
+        Passive Indicative Perfective Past:       N      N        N        N        N         N
+        Passive Indicative Perfective Future:     N      N        N        N        N         N
+        Passive Subjunctive Imperfective Present: "er"   "ēris"   "ētur"   "ēmur"   "ēminī"   "entur"
+        Passive Subjunctive Imperfective Past:    "ārer" "ārēris" "ārētur" "ārēmur" "ārēminī" "ārentur"
+        Passive Subjunctive Imperfective Future:  N      N        N        N        N         N
+        Passive Subjunctive Perfective Present:   N      N        N        N        N         N
+        Passive Subjunctive Perfective Past:      N      N        N        N        N         N
+        Passive Subjunctive Perfective Future:    N      N        N        N        N         N
+        Passive Imperative Imperfective Present:  N      "āre"    N        N        "āminī"   N
+        Passive Imperative Imperfective Past:     N      N        N        N        N         N
+        Passive Imperative Imperfective Future:   N      "ātor"   "ātor"   N        N         "antor"
+        Passive Imperative Perfective Present:    N      N        N        N        N         N
+        Passive Imperative Perfective Past:       N      N        N        N        N         N
+        Passive Imperative Perfective Future:     N      N        N        N        N         N
+        Passive Participle Imperfective Present:  N      N        N        N        N         N
+        Passive Participle Imperfective Past:     N      N        N        N        N         N
+        Passive Participle Imperfective Future:   N      N        "andus"  N        N         N
+        Passive Participle Perfective Present:    N      N        N        N        N         N
+        Passive Participle Perfective Past:       N      N        N        N        N         N
+        Passive Participle Perfective Future:     N      N        N        N        N         N
+    ],
+};
+
+pub const SECOND_CONJUGATION: VerbConjugation = VerbConjugation {
+    name: "Second Conjugation",
+    suffixes: suffixes! [VerbCategories =>
+        Active Indicative Imperfective Present:   "eō"   "ēs"     "et"     "ēmus"   "ētis"    "ent"
+        Active Indicative Imperfective Past:      "ēbam" "ēbās"   "ēbat"   "ēbāmus" "ēbātis"  "ēbant"
+        Active Indicative Imperfective Future:    "ēbō"  "ēbis"   "ēbit"   "ēbimus" "ēbitis"  "ēbunt"
+        Active Indicative Perfective Present:     N      N        N        N        N         N
+        Active Indicative Perfective Past:        N      N        N        N        N         N
+        Active Indicative Perfective Future:      N      N        N        N        N         N
+        Active Subjunctive Imperfective Present:  "eam"  "eās"    "eat"    "eāmus"  "eātis"   "eant"
+        Active Subjunctive Imperfective Past:     "ērem" "ērēs"   "ēret"   "ērēmus" "ērētis"  "ērent"
+        Active Subjunctive Imperfective Future:   N      N        N        N        N         N
+        Active Subjunctive Perfective Present:    N      N        N        N        N         N
+        Active Subjunctive Perfective Past:       N      N        N        N        N         N
+        Active Subjunctive Perfective Future:     N      N        N        N        N         N
+        Active Imperative Imperfective Present:   N      "ē"      N        N        "ēte"     N
+        Active Imperative Imperfective Past:      N      N        N        N        N         N
+        Active Imperative Imperfective Future:    N      "ētō"    "ētō"    N        "ētōte"   "entō"
+        Active Imperative Perfective Present:     N      N        N        N        N         N
+        Active Imperative Perfective Past:        N      N        N        N        N         N
+        Active Imperative Perfective Future:      N      N        N        N        N         N
+        Active Participle Imperfective Present:   N      N        "ēns"    N        N         N
+        Active Participle Imperfective Past:      N      N        N        N        N         N
+        Active Participle Imperfective Future:    N      N        N        N        N         N
+        Active Participle Perfective Present:     N      N        N        N        N         N
+        Active Participle Perfective Past:        N      N        N        N        N         N
+        Active Participle Perfective Future:      N      N        N        N        N         N
+        Passive Indicative Imperfective Present:  "eor"  "ēris"   "ētur"   "ēmur"   "ēminī"   "entur"
+        Passive Indicative Imperfective Past:     "ēbar" "ēbāris" "ēbātur" "ēbāmur" "ēbāminī" "ēbantur"
+        Passive Indicative Imperfective Future:   "ēbor" "ēberis" "ēbitur" "ēbimur" "ēbiminī" "ēbuntur"
+        Passive Indicative Perfective Present:    N      N        N        N        N         N
+        Passive Indicative Perfective Past:       N      N        N        N        N         N
+        Passive Indicative Perfective Future:     N      N        N        N        N         N
+        Passive Subjunctive Imperfective Present: "ear"  "eāris"  "eātur"  "eāmur"  "eāminī"  "eantur"
+        Passive Subjunctive Imperfective Past:    "ērer" "ērēris" "ērētur" "ērēmur" "ērēminī" "ērentur"
+        Passive Subjunctive Imperfective Future:  N      N        N        N        N         N
+        Passive Subjunctive Perfective Present:   N      N        N        N        N         N
+        Passive Subjunctive Perfective Past:      N      N        N        N        N         N
+        Passive Subjunctive Perfective Future:    N      N        N        N        N         N
+        Passive Imperative Imperfective Present:  N      "ēre"    N        N        "ēminī"   N
+        Passive Imperative Imperfective Past:     N      N        N        N        N         N
+        Passive Imperative Imperfective Future:   N      "ētor"   "ētor"   N        N         "entor"
+        Passive Imperative Perfective Present:    N      N        N        N        N         N
+        Passive Imperative Perfective Past:       N      N        N        N        N         N
+        Passive Imperative Perfective Future:     N      N        N        N        N         N
+        Passive Participle Imperfective Present:  N      N        N        N        N         N
+        Passive Participle Imperfective Past:     N      N        N        N        N         N
+        Passive Participle Imperfective Future:   N      N        "endus"  N        N         N
+        Passive Participle Perfective Present:    N      N        N        N        N         N
+        Passive Participle Perfective Past:       N      N        N        N        N         N
+        Passive Participle Perfective Future:     N      N        N        N        N         N
+    ],
+};
+
+pub const THIRD_CONJUGATION: VerbConjugation = VerbConjugation {
+    name: "Third Conjugation",
+    suffixes: suffixes! [VerbCategories =>
+        Active Indicative Imperfective Present:   "ō"    "is"     "it"     "imus"   "itis"    "unt"
+        Active Indicative Imperfective Past:      "ēbam" "ēbās"   "ēbat"   "ēbāmus" "ēbātis"  "ēbant"
+        Active Indicative Imperfective Future:    "am"   "ēs"     "et"     "ēmus"   "ētis"    "ent"
+        Active Indicative Perfective Present:     N      N        N        N        N         N
+        Active Indicative Perfective Past:        N      N        N        N        N         N
+        Active Indicative Perfective Future:      N      N        N        N        N         N
+        Active Subjunctive Imperfective Present:  "am"   "ās"     "at"     "āmus"   "ātis"    "ant"
+        Active Subjunctive Imperfective Past:     "erem" "erēs"   "eret"   "erēmus" "erētis"  "erent"
+        Active Subjunctive Imperfective Future:   N      N        N        N        N         N
+        Active Subjunctive Perfective Present:    N      N        N        N        N         N
+        Active Subjunctive Perfective Past:       N      N        N        N        N         N
+        Active Subjunctive Perfective Future:     N      N        N        N        N         N
+        Active Imperative Imperfective Present:   N      "e"      N        N        "ite"     N
+        Active Imperative Imperfective Past:      N      N        N        N        N         N
+        Active Imperative Imperfective Future:    N      "itō"    "itō"    N        "itōte"   "untō"
+        Active Imperative Perfective Present:     N      N        N        N        N         N
+        Active Imperative Perfective Past:        N      N        N        N        N         N
+        Active Imperative Perfective Future:      N      N        N        N        N         N
+        Active Participle Imperfective Present:   N      N        "ēns"    N        N         N
+        Active Participle Imperfective Past:      N      N        N        N        N         N
+        Active Participle Imperfective Future:    N      N        N        N        N         N
+        Active Participle Perfective Present:     N      N        N        N        N         N
+        Active Participle Perfective Past:        N      N        N        N        N         N
+        Active Participle Perfective Future:      N      N        N        N        N         N
+        Passive Indicative Imperfective Present:  "or"   "eris"   "itur"   "imur"   "iminī"   "untur"
+        Passive Indicative Imperfective Past:     "ēbar" "ēbāris" "ēbātur" "ēbāmur" "ēbāminī" "ēbantur"
+        Passive Indicative Imperfective Future:   "ar"   "ēris"   "ētur"   "ēmur"   "ēminī"   "entur"
+        Passive Indicative Perfective Present:    N      N        N        N        N         N
+        Passive Indicative Perfective Past:       N      N        N        N        N         N
+        Passive Indicative Perfective Future:     N      N        N        N        N         N
+        Passive Subjunctive Imperfective Present: "ar"   "āris"   "ātur"   "āmur"   "āminī"   "antur"
+        Passive Subjunctive Imperfective Past:    "erer" "erēris" "erētur" "erēmur" "erēminī" "erentur"
+        Passive Subjunctive Imperfective Future:  N      N        N        N        N         N
+        Passive Subjunctive Perfective Present:   N      N        N        N        N         N
+        Passive Subjunctive Perfective Past:      N      N        N        N        N         N
+        Passive Subjunctive Perfective Future:    N      N        N        N        N         N
+        Passive Imperative Imperfective Present:  N      "ere"    N        N        "iminī"   N
+        Passive Imperative Imperfective Past:     N      N        N        N        N         N
+        Passive Imperative Imperfective Future:   N      "itor"   "itor"   N        N         "untor"
+        Passive Imperative Perfective Present:    N      N        N        N        N         N
+        Passive Imperative Perfective Past:       N      N        N        N        N         N
+        Passive Imperative Perfective Future:     N      N        N        N        N         N
+        Passive Participle Imperfective Present:  N      N        N        N        N         N
+        Passive Participle Imperfective Past:     N      N        N        N        N         N
+        Passive Participle Imperfective Future:   N      N        "endus"  N        N         N
+        Passive Participle Perfective Present:    N      N        N        N        N         N
+        Passive Participle Perfective Past:       N      N        N        N        N         N
+        Passive Participle Perfective Future:     N      N        N        N        N         N
+    ],
+};
+
+pub const THIRD_IO_CONJUGATION: VerbConjugation = VerbConjugation {
+    name: "Third Conjugation (-iō)",
+    suffixes: suffixes! [VerbCategories =>
+        Active Indicative Imperfective Present:   "iō"    "is"      "it"      "imus"    "itis"     "iunt"
+        Active Indicative Imperfective Past:      "iēbam" "iēbās"   "iēbat"   "iēbāmus" "iēbātis"  "iēbant"
+        Active Indicative Imperfective Future:    "iam"   "iēs"     "iet"     "iēmus"   "iētis"    "ient"
+        Active Indicative Perfective Present:     N       N         N         N         N          N
+        Active Indicative Perfective Past:        N       N         N         N         N          N
+        Active Indicative Perfective Future:      N       N         N         N         N          N
+        Active Subjunctive Imperfective Present:  "iam"   "iās"     "iat"     "iāmus"   "iātis"    "iant"
+        Active Subjunctive Imperfective Past:     "erem"  "erēs"    "eret"    "erēmus"  "erētis"   "erent"
+        Active Subjunctive Imperfective Future:   N       N         N         N         N          N
+        Active Subjunctive Perfective Present:    N       N         N         N         N          N
+        Active Subjunctive Perfective Past:       N       N         N         N         N          N
+        Active Subjunctive Perfective Future:     N       N         N         N         N          N
+        Active Imperative Imperfective Present:   N       "e"       N         N         "ite"      N
+        Active Imperative Imperfective Past:      N       N         N         N         N          N
+        Active Imperative Imperfective Future:    N       "itō"     "itō"     N         "itōte"    "iuntō"
+        Active Imperative Perfective Present:     N       N         N         N         N          N
+        Active Imperative Perfective Past:        N       N         N         N         N          N
+        Active Imperative Perfective Future:      N       N         N         N         N          N
+        Active Participle Imperfective Present:   N       N         "iēns"    N         N          N
+        Active Participle Imperfective Past:      N       N         N         N         N          N
+        Active Participle Imperfective Future:    N       N         N         N         N          N
+        Active Participle Perfective Present:     N       N         N         N         N          N
+        Active Participle Perfective Past:        N       N         N         N         N          N
+        Active Participle Perfective Future:      N       N         N         N         N          N
+        Passive Indicative Imperfective Present:  "ior"   "eris"    "itur"    "imur"    "iminī"    "iuntur"
+        Passive Indicative Imperfective Past:     "iēbar" "iēbāris" "iēbātur" "iēbāmur" "iēbāminī" "iēbantur"
+        Passive Indicative Imperfective Future:   "iar"   "iēris"   "iētur"   "iēmur"   "iēminī"   "ientur"
+        Passive Indicative Perfective Present:    N       N         N         N         N          N
+        Passive Indicative Perfective Past:       N       N         N         N         N          N
+        Passive Indicative Perfective Future:     N       N         N         N         N          N
+        Passive Subjunctive Imperfective Present: "iar"   "iāris"   "iātur"   "iāmur"   "iāminī"   "iantur"
+        Passive Subjunctive Imperfective Past:    "erer"  "erēris"  "erētur"  "erēmur"  "erēminī"  "erentur"
+        Passive Subjunctive Imperfective Future:  N       N         N         N         N          N
+        Passive Subjunctive Perfective Present:   N       N         N         N         N          N
+        Passive Subjunctive Perfective Past:      N       N         N         N         N          N
+        Passive Subjunctive Perfective Future:    N       N         N         N         N          N
+        Passive Imperative Imperfective Present:  N       "ere"     N         N         "iminī"    N
+        Passive Imperative Imperfective Past:     N       N         N         N         N          N
+        Passive Imperative Imperfective Future:   N       "itor"    "itor"    N         N          "iuntor"
+        Passive Imperative Perfective Present:    N       N         N         N         N          N
+        Passive Imperative Perfective Past:       N       N         N         N         N          N
+        Passive Imperative Perfective Future:     N       N         N         N         N          N
+        Passive Participle Imperfective Present:  N       N         N         N         N          N
+        Passive Participle Imperfective Past:     N       N         N         N         N          N
+        Passive Participle Imperfective Future:   N       N         "iendus"  N         N          N
+        Passive Participle Perfective Present:    N       N         N         N         N          N
+        Passive Participle Perfective Past:       N       N         N         N         N          N
+        Passive Participle Perfective Future:     N       N         N         N         N          N
+    ],
+};
+
+pub const FOURTH_CONJUGATION: VerbConjugation = VerbConjugation {
+    name: "Fourth Conjugation",
+    suffixes: suffixes! [VerbCategories =>
+        Active Indicative Imperfective Present:   "iō"    "īs"      "it"      "īmus"    "ītis"     "iunt"
+        Active Indicative Imperfective Past:      "iēbam" "iēbās"   "iēbat"   "iēbāmus" "iēbātis"  "iēbant"
+        Active Indicative Imperfective Future:    "iam"   "iēs"     "iet"     "iēmus"   "iētis"    "ient"
+        Active Indicative Perfective Present:     N       N         N         N         N          N
+        Active Indicative Perfective Past:        N       N         N         N         N          N
+        Active Indicative Perfective Future:      N       N         N         N         N          N
+        Active Subjunctive Imperfective Present:  "iam"   "iās"     "iat"     "iāmus"   "iātis"    "iant"
+        Active Subjunctive Imperfective Past:     "īrem"  "īrēs"    "īret"    "īrēmus"  "īrētis"   "īrent"
+        Active Subjunctive Imperfective Future:   N       N         N         N         N          N
+        Active Subjunctive Perfective Present:    N       N         N         N         N          N
+        Active Subjunctive Perfective Past:       N       N         N         N         N          N
+        Active Subjunctive Perfective Future:     N       N         N         N         N          N
+        Active Imperative Imperfective Present:   N       "ī"       N         N         "īte"      N
+        Active Imperative Imperfective Past:      N       N         N         N         N          N
+        Active Imperative Imperfective Future:    N       "ītō"     "ītō"     N         "ītōte"    "iuntō"
+        Active Imperative Perfective Present:     N       N         N         N         N          N
+        Active Imperative Perfective Past:        N       N         N         N         N          N
+        Active Imperative Perfective Future:      N       N         N         N         N          N
+        Active Participle Imperfective Present:   N       N         "iēns"    N         N          N
+        Active Participle Imperfective Past:      N       N         N         N         N          N
+        Active Participle Imperfective Future:    N       N         N         N         N          N
+        Active Participle Perfective Present:     N       N         N         N         N          N
+        Active Participle Perfective Past:        N       N         N         N         N          N
+        Active Participle Perfective Future:      N       N         N         N         N          N
+        Passive Indicative Imperfective Present:  "ior"   "īris"    "ītur"    "īmur"    "īminī"    "iuntur"
+        Passive Indicative Imperfective Past:     "iēbar" "iēbāris" "iēbātur" "iēbāmur" "iēbāminī" "iēbantur"
+        Passive Indicative Imperfective Future:   "iar"   "iēris"   "iētur"   "iēmur"   "iēminī"   "ientur"
+        Passive Indicative Perfective Present:    N       N         N         N         N          N
+        Passive Indicative Perfective Past:       N       N         N         N         N          N
+        Passive Indicative Perfective Future:     N       N         N         N         N          N
+        Passive Subjunctive Imperfective Present: "iar"   "iāris"   "iātur"   "iāmur"   "iāminī"   "iantur"
+        Passive Subjunctive Imperfective Past:    "īrer"  "īrēris"  "īrētur"  "īrēmur"  "īrēminī"  "īrentur"
+        Passive Subjunctive Imperfective Future:  N       N         N         N         N          N
+        Passive Subjunctive Perfective Present:   N       N         N         N         N          N
+        Passive Subjunctive Perfective Past:      N       N         N         N         N          N
+        Passive Subjunctive Perfective Future:    N       N         N         N         N          N
+        Passive Imperative Imperfective Present:  N       "īre"     N         N         "īminī"    N
+        Passive Imperative Imperfective Past:     N       N         N         N         N          N
+        Passive Imperative Imperfective Future:   N       "ītor"    "ītor"    N         N          "iuntor"
+        Passive Imperative Perfective Present:    N       N         N         N         N          N
+        Passive Imperative Perfective Past:       N       N         N         N         N          N
+        Passive Imperative Perfective Future:     N       N         N         N         N          N
+        Passive Participle Imperfective Present:  N       N         N         N         N          N
+        Passive Participle Imperfective Past:     N       N         N         N         N          N
+        Passive Participle Imperfective Future:   N       N         "iendus"  N         N          N
+        Passive Participle Perfective Present:    N       N         N         N         N          N
+        Passive Participle Perfective Past:       N       N         N         N         N          N
+        Passive Participle Perfective Future:     N       N         N         N         N          N
+    ],
+};
+
+pub const PERFECT_SYSTEM: VerbConjugation = VerbConjugation {
+    name: "Perfect System",
+    suffixes: suffixes! [VerbCategories =>
+        Active Indicative Imperfective Present:   N       N       N       N         N         N
+        Active Indicative Imperfective Past:      N       N       N       N         N         N
+        Active Indicative Imperfective Future:    N       N       N       N         N         N
+        Active Indicative Perfective Present:     "ī"     "istī"  "it"    "imus"    "istis"   "ērunt"|"ēre"
+        Active Indicative Perfective Past:        "eram"  "erās"  "erat"  "erāmus"  "erātis"  "erant"
+        Active Indicative Perfective Future:      "erō"   "eris"  "erit"  "erimus"  "eritis"  "erint"
+        Active Subjunctive Imperfective Present:  N       N       N       N         N         N
+        Active Subjunctive Imperfective Past:     N       N       N       N         N         N
+        Active Subjunctive Imperfective Future:   N       N       N       N         N         N
+        Active Subjunctive Perfective Present:    "erim"  "erīs"  "erit"  "erīmus"  "erītis"  "erint"
+        Active Subjunctive Perfective Past:       "issem" "issēs" "isset" "issēmus" "issētis" "issent"
+        Active Subjunctive Perfective Future:     N       N       N       N         N         N
+        Active Imperative Imperfective Present:   N       N       N       N         N         N
+        Active Imperative Imperfective Past:      N       N       N       N         N         N
+        Active Imperative Imperfective Future:    N       N       N       N         N         N
+        Active Imperative Perfective Present:     N       N       N       N         N         N
+        Active Imperative Perfective Past:        N       N       N       N         N         N
+        Active Imperative Perfective Future:      N       N       N       N         N         N
+        Active Participle Imperfective Present:   N       N       N       N         N         N
+        Active Participle Imperfective Past:      N       N       N       N         N         N
+        Active Participle Imperfective Future:    N       N       N       N         N         N
+        Active Participle Perfective Present:     N       N       N       N         N         N
+        Active Participle Perfective Past:        N       N       N       N         N         N
+        Active Participle Perfective Future:      N       N       N       N         N         N
+        Passive Indicative Imperfective Present:  N       N       N       N         N         N
+        Passive Indicative Imperfective Past:     N       N       N       N         N         N
+        Passive Indicative Imperfective Future:   N       N       N       N         N         N
+        Passive Indicative Perfective Present:    N       N       N       N         N         N
+        Passive Indicative Perfective Past:       N       N       N       N         N         N
+        Passive Indicative Perfective Future:     N       N       N       N         N         N
+        Passive Subjunctive Imperfective Present: N       N       N       N         N         N
+        Passive Subjunctive Imperfective Past:    N       N       N       N         N         N
+        Passive Subjunctive Imperfective Future:  N       N       N       N         N         N
+        Passive Subjunctive Perfective Present:   N       N       N       N         N         N
+        Passive Subjunctive Perfective Past:      N       N       N       N         N         N
+        Passive Subjunctive Perfective Future:    N       N       N       N         N         N
+        Passive Imperative Imperfective Present:  N       N       N       N         N         N
+        Passive Imperative Imperfective Past:     N       N       N       N         N         N
+        Passive Imperative Imperfective Future:   N       N       N       N         N         N
+        Passive Imperative Perfective Present:    N       N       N       N         N         N
+        Passive Imperative Perfective Past:       N       N       N       N         N         N
+        Passive Imperative Perfective Future:     N       N       N       N         N         N
+        Passive Participle Imperfective Present:  N       N       N       N         N         N
+        Passive Participle Imperfective Past:     N       N       N       N         N         N
+        Passive Participle Imperfective Future:   N       N       N       N         N         N
+        Passive Participle Perfective Present:    N       N       N       N         N         N
+        Passive Participle Perfective Past:       N       N       N       N         N         N
+        Passive Participle Perfective Future:     N       N       N       N         N         N
+    ],
+};
+
+pub const SUPINE_SYSTEM: VerbConjugation = VerbConjugation {
+    name: "Supine System",
+    suffixes: suffixes! [VerbCategories =>
+        Active Indicative Imperfective Present:   N          N          N          N           N           N
+        Active Indicative Imperfective Past:      N          N          N          N           N           N
+        Active Indicative Imperfective Future:    N          N          N          N           N           N
+        Active Indicative Perfective Present:     N          N          N          N           N           N
+        Active Indicative Perfective Past:        N          N          N          N           N           N
+        Active Indicative Perfective Future:      N          N          N          N           N           N
+        Active Subjunctive Imperfective Present:  N          N          N          N           N           N
+        Active Subjunctive Imperfective Past:     N          N          N          N           N           N
+        Active Subjunctive Imperfective Future:   N          N          N          N           N           N
+        Active Subjunctive Perfective Present:    N          N          N          N           N           N
+        Active Subjunctive Perfective Past:       N          N          N          N           N           N
+        Active Subjunctive Perfective Future:     N          N          N          N           N           N
+        Active Imperative Imperfective Present:   N          N          N          N           N           N
+        Active Imperative Imperfective Past:      N          N          N          N           N           N
+        Active Imperative Imperfective Future:    N          N          N          N           N           N
+        Active Imperative Perfective Present:     N          N          N          N           N           N
+        Active Imperative Perfective Past:        N          N          N          N           N           N
+        Active Imperative Perfective Future:      N          N          N          N           N           N
+        Active Participle Imperfective Present:   N          N          N          N           N           N
+        Active Participle Imperfective Past:      N          N          N          N           N           N
+        Active Participle Imperfective Future:    N          N          "ūrus"     N           N           N
+        Active Participle Perfective Present:     N          N          N          N           N           N
+        Active Participle Perfective Past:        N          N          N          N           N           N
+        Active Participle Perfective Future:      N          N          N          N           N           N
+        Passive Indicative Imperfective Present:  N          N          N          N           N           N
+        Passive Indicative Imperfective Past:     N          N          N          N           N           N
+        Passive Indicative Imperfective Future:   N          N          N          N           N           N
+        Passive Indicative Perfective Present:    "us sum"   "us es"    "us est"   "ī sumus"   "ī estis"   "ī sunt"
+        Passive Indicative Perfective Past:       "us eram"  "us erās"  "us erat"  "ī erāmus"  "ī erātis"  "ī erant"
+        Passive Indicative Perfective Future:     "us erō"   "us eris"  "us erit"  "ī erimus"  "ī eritis"  "ī erunt"
+        Passive Subjunctive Imperfective Present: N          N          N          N           N           N
+        Passive Subjunctive Imperfective Past:    N          N          N          N           N           N
+        Passive Subjunctive Imperfective Future:  N          N          N          N           N           N
+        Passive Subjunctive Perfective Present:   "us sim"   "us sīs"   "us sit"   "ī sīmus"   "ī sītis"   "ī sint"
+        Passive Subjunctive Perfective Past:      "us essem" "us essēs" "us esset" "ī essēmus" "ī essētis" "ī essent"
+        Passive Subjunctive Perfective Future:    N          N          N          N           N           N
+        Passive Imperative Imperfective Present:  N          N          N          N           N           N
+        Passive Imperative Imperfective Past:     N          N          N          N           N           N
+        Passive Imperative Imperfective Future:   N          N          N          N           N           N
+        Passive Imperative Perfective Present:    N          N          N          N           N           N
+        Passive Imperative Perfective Past:       N          N          N          N           N           N
+        Passive Imperative Perfective Future:     N          N          N          N           N           N
+        Passive Participle Imperfective Present:  N          N          N          N           N           N
+        Passive Participle Imperfective Past:     N          N          N          N           N           N
+        Passive Participle Imperfective Future:   N          N          N          N           N           N
+        Passive Participle Perfective Present:    N          N          "us"       N           N           N
+        Passive Participle Perfective Past:       N          N          N          N           N           N
+        Passive Participle Perfective Future:     N          N          N          N           N           N
+    ],
+};
+
+/// Every conjugation of the present system, followed by the perfect and supine systems.
+pub static CONJUGATIONS: [VerbConjugation; 7] = [
+    FIRST_CONJUGATION,
+    SECOND_CONJUGATION,
+    THIRD_CONJUGATION,
+    THIRD_IO_CONJUGATION,
+    FOURTH_CONJUGATION,
+    PERFECT_SYSTEM,
+    SUPINE_SYSTEM,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suffixes() {
+        let amo = crate::Word::new(FIRST_CONJUGATION, "amō", "am");
+        let moneo = crate::Word::new(SECOND_CONJUGATION, "moneō", "mon");
+        let rego = crate::Word::new(THIRD_CONJUGATION, "regō", "reg");
+        let capio = crate::Word::new(THIRD_IO_CONJUGATION, "capiō", "cap");
+        let audio = crate::Word::new(FOURTH_CONJUGATION, "audiō", "aud");
+
+        let present = VerbCategories(Voice::Active, Mood::Indicative, Aspect::Imperfective, Tense::Present, Number::Plural, Person::Third);
+        assert_eq!(amo.inflect(present).unwrap(), "amant");
+        assert_eq!(moneo.inflect(present).unwrap(), "monent");
+        assert_eq!(rego.inflect(present).unwrap(), "regunt");
+        assert_eq!(capio.inflect(present).unwrap(), "capiunt");
+        assert_eq!(audio.inflect(present).unwrap(), "audiunt");
+
+        let future = VerbCategories(Voice::Passive, Mood::Indicative, Aspect::Imperfective, Tense::Future, Number::Singular, Person::Second);
+        assert_eq!(amo.inflect(future).unwrap(), "amāberis");
+        assert_eq!(rego.inflect(future).unwrap(), "regēris");
+        assert_eq!(audio.inflect(future).unwrap(), "audiēris");
+
+        let imperfect_subjunctive = VerbCategories(Voice::Active, Mood::Subjunctive, Aspect::Imperfective, Tense::Past, Number::Singular, Person::First);
+        assert_eq!(capio.inflect(imperfect_subjunctive).unwrap(), "caperem");
+
+        let imperative = VerbCategories(Voice::Active, Mood::Imperative, Aspect::Imperfective, Tense::Present, Number::Singular, Person::Second);
+        assert_eq!(moneo.inflect(imperative).unwrap(), "monē");
+        assert_eq!(amo.inflect(VerbCategories(Voice::Active, Mood::Imperative, Aspect::Imperfective, Tense::Present, Number::Singular, Person::First)), Err(InflectionError::NotInInflection));
+
+        let perfect = VerbCategories(Voice::Active, Mood::Indicative, Aspect::Perfective, Tense::Present, Number::Singular, Person::First);
+        assert_eq!(amo.inflect(perfect), Err(InflectionError::NotInInflection));
+        assert_eq!(VerbCategories(Voice::Passive, Mood::Participle, Aspect::Imperfective, Tense::Future, Number::Singular, Person::Third).index(), 266);
+    }
 }
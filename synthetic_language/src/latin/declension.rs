@@ -28,102 +28,188 @@ suffix_inflection_over_categories! {
     }
 }
 
+impl NominalCategories {
+    /// Whether these are locative categories. Every table gives a locative, but only the names of
+    /// cities and small islands and a few nouns such as "domus" and "rūs" have one, so most nouns
+    /// are built with `.defective(NominalCategories::is_locative)`.
+    pub fn is_locative(&self) -> bool {
+        self.2 == Case::Locative
+    }
+
+    /// Whether these are singular categories, in which pluralia tantum such as "castra" are
+    /// defective.
+    pub fn is_singular(&self) -> bool {
+        self.1 == Number::Singular
+    }
+
+    /// Whether these are plural categories, in which singularia tantum such as "aurum" are
+    /// defective.
+    pub fn is_plural(&self) -> bool {
+        self.1 == Number::Plural
+    }
+}
+
 pub const FIRST_DECLENSION: NominalDeclension = NominalDeclension {
     name: "First Declension",
-    suffixes: suffixes! [
-        "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
-        "ae" "arum" "īs" "ās" "īs" "ae" "īs"
-        "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
-        "ae" "arum" "īs" "ās" "īs" "ae" "īs"
-        "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
-        "ae" "arum" "īs" "ās" "īs" "ae" "īs"
-        N    N      N    N    N    N    N
-        N    N      N    N    N    N    N
+    suffixes: suffixes! [NominalCategories =>
+        Feminine Singular:  "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
+        Feminine Plural:    "ae" "ārum" "īs" "ās" "īs" "ae" "īs"
+        Masculine Singular: "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
+        Masculine Plural:   "ae" "ārum" "īs" "ās" "īs" "ae" "īs"
+        Common Singular:    "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
+        Common Plural:      "ae" "ārum" "īs" "ās" "īs" "ae" "īs"
+        Neuter Singular:    N    N      N    N    N    N    N
+        Neuter Plural:      N    N      N    N    N    N    N
     ],
 };
 
 pub const SECOND_DECLENSION: NominalDeclension = NominalDeclension {
     name: "Second Declension",
-    suffixes: suffixes! [
-        "us" "ī"    "o"  "um" "o"  "e"  "ī"
-        "ī"  "ōrum" "īs" "ōs" "īs" "ī"  "īs"
-        "us" "ī"    "o"  "um" "o"  "e"  "ī"
-        "ī"  "ōrum" "īs" "ōs" "īs" "ī"  "īs"
-        "us" "ī"    "o"  "um" "o"  "e"  "ī"
-        "ī"  "ōrum" "īs" "ōs" "īs" "ī"  "īs"
-        "um" "ī"    "o"  "um" "o"  "um" "ī"
-        "a"  "ōrum" "īs" "a" "īs"  "a"  "īs"
+    suffixes: suffixes! [NominalCategories =>
+        Feminine Singular:  "us" "ī"    "ō"  "um" "ō"  "e"  "ī"
+        Feminine Plural:    "ī"  "ōrum" "īs" "ōs" "īs" "ī"  "īs"
+        Masculine Singular: "us" "ī"    "ō"  "um" "ō"  "e"  "ī"
+        Masculine Plural:   "ī"  "ōrum" "īs" "ōs" "īs" "ī"  "īs"
+        Common Singular:    "us" "ī"    "ō"  "um" "ō"  "e"  "ī"
+        Common Plural:      "ī"  "ōrum" "īs" "ōs" "īs" "ī"  "īs"
+        Neuter Singular:    "um" "ī"    "ō"  "um" "ō"  "um" "ī"
+        Neuter Plural:      "a"  "ōrum" "īs" "a" "īs"  "a"  "īs"
     ],
 };
 
+/// Consonant stems, such as "rēx, rēgis" and "corpus, corporis".
 pub const THIRD_DECLENSION: NominalDeclension = NominalDeclension {
     name: "Third Declension",
-    suffixes: suffixes! [
-        "is"   "is"   "ī"    "em" "e"    "is" "ī"
-        "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
-        "is"   "is"   "ī"    "em" "e"    "is" "ī"
-        "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
-        "is"   "is"   "ī"    "em" "e"    "is" "ī"
-        "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
-        "e"    "is"   "ī"    "e"  "e"    "e"  "ī"
-        "a"    "um"   "ibus" "a"  "ibus" "a"  "ibus"
+    suffixes: suffixes! [NominalCategories =>
+        Feminine Singular:  L      "is"   "ī"    "em" "e"    L    "ī"
+        Feminine Plural:    "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
+        Masculine Singular: L      "is"   "ī"    "em" "e"    L    "ī"
+        Masculine Plural:   "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
+        Common Singular:    L      "is"   "ī"    "em" "e"    L    "ī"
+        Common Plural:      "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
+        Neuter Singular:    L      "is"   "ī"    L    "e"    L    "ī"
+        Neuter Plural:      "a"    "um"   "ibus" "a"  "ibus" "a"  "ibus"
     ],
 };
 
+/// Pure i-stems, such as "turris, turris" and the neuters in -e, -al and -ar such as "mare, maris"
+/// and "animal, animālis".
+pub const THIRD_I_STEM_DECLENSION: NominalDeclension = NominalDeclension {
+    name: "Third Declension (I-Stem)",
+    suffixes: suffixes! [NominalCategories =>
+        Feminine Singular:  L      "is"   "ī"    "im"|"em" "ī"|"e" L    "ī"
+        Feminine Plural:    "ēs"   "ium"  "ibus" "īs"|"ēs" "ibus"  "ēs" "ibus"
+        Masculine Singular: L      "is"   "ī"    "im"|"em" "ī"|"e" L    "ī"
+        Masculine Plural:   "ēs"   "ium"  "ibus" "īs"|"ēs" "ibus"  "ēs" "ibus"
+        Common Singular:    L      "is"   "ī"    "im"|"em" "ī"|"e" L    "ī"
+        Common Plural:      "ēs"   "ium"  "ibus" "īs"|"ēs" "ibus"  "ēs" "ibus"
+        Neuter Singular:    L      "is"   "ī"    L         "ī"     L    "ī"
+        Neuter Plural:      "ia"   "ium"  "ibus" "ia"      "ibus"  "ia" "ibus"
+    ],
+};
+
+/// Mixed i-stems, such as "cīvis, cīvis", "nūbēs, nūbis" and "urbs, urbis", which decline as
+/// consonant stems except in the genitive and accusative plural.
+pub const THIRD_MIXED_DECLENSION: NominalDeclension = THIRD_DECLENSION
+    .with_name("Third Declension (Mixed I-Stem)")
+    .with_cell(NominalCategories(Gender::Feminine, Number::Plural, Case::Genitive), &[Suffix::Ending("ium")])
+    .with_cell(NominalCategories(Gender::Feminine, Number::Plural, Case::Accusative), &[Suffix::Ending("ēs"), Suffix::Ending("īs")])
+    .with_cell(NominalCategories(Gender::Masculine, Number::Plural, Case::Genitive), &[Suffix::Ending("ium")])
+    .with_cell(NominalCategories(Gender::Masculine, Number::Plural, Case::Accusative), &[Suffix::Ending("ēs"), Suffix::Ending("īs")])
+    .with_cell(NominalCategories(Gender::Common, Number::Plural, Case::Genitive), &[Suffix::Ending("ium")])
+    .with_cell(NominalCategories(Gender::Common, Number::Plural, Case::Accusative), &[Suffix::Ending("ēs"), Suffix::Ending("īs")])
+    .with_cell(NominalCategories(Gender::Neuter, Number::Plural, Case::Genitive), &[Suffix::Ending("ium")]);
+
 pub const FOURTH_DECLENSION: NominalDeclension = NominalDeclension {
     name: "Fourth Declension",
-    suffixes: suffixes! [
-        "us"  "ūs"   "uī"   "um" "ū"    "us" N
-        "ūs"  "uum"  "ibus" "ūs" "ibus" "ūs" N
-        "us"  "ūs"   "uī"   "um" "ū"    "us" N
-        "ūs"  "uum"  "ibus" "ūs" "ibus" "ūs" N
-        "us"  "ūs"   "uī"   "um" "ū"    "us" N
-        "ūs"  "uum"  "ibus" "ūs" "ibus" "ūs" N
-        "ū"   "ūs"   "ū"    "ū"  "ū"    "ū"  N
-        "ua"  "uum"  "ibus" "ua" "ibus" "ua" N
+    suffixes: suffixes! [NominalCategories =>
+        Feminine Singular:  "us"  "ūs"   "uī"   "um" "ū"    "us" N
+        Feminine Plural:    "ūs"  "uum"  "ibus" "ūs" "ibus" "ūs" N
+        Masculine Singular: "us"  "ūs"   "uī"   "um" "ū"    "us" N
+        Masculine Plural:   "ūs"  "uum"  "ibus" "ūs" "ibus" "ūs" N
+        Common Singular:    "us"  "ūs"   "uī"   "um" "ū"    "us" N
+        Common Plural:      "ūs"  "uum"  "ibus" "ūs" "ibus" "ūs" N
+        Neuter Singular:    "ū"   "ūs"   "ū"    "ū"  "ū"    "ū"  N
+        Neuter Plural:      "ua"  "uum"  "ibus" "ua" "ibus" "ua" N
     ],
 };
 
 pub const FIFTH_DECLENSION: NominalDeclension = NominalDeclension {
     name: "Fifth Declension",
-    suffixes: suffixes! [
-        "ēs" "ēī"   "ēī"   "em" "ē"    "ēs" N
-        "ēs" "ērum" "ēbus" "ēs" "ēbus" "ēs" N
-        "ēs" "ēī"   "ēī"   "em" "ē"    "ēs" N
-        "ēs" "ērum" "ēbus" "ēs" "ēbus" "ēs" N
-        "ēs" "ēī"   "ēī"   "em" "ē"    "ēs" N
-        "ēs" "ērum" "ēbus" "ēs" "ēbus" "ēs" N
-        N    N      N      N    N      N    N
-        N    N      N      N    N      N    N
+    suffixes: suffixes! [NominalCategories =>
+        Feminine Singular:  "ēs" "ēī"   "ēī"   "em" "ē"    "ēs" N
+        Feminine Plural:    "ēs" "ērum" "ēbus" "ēs" "ēbus" "ēs" N
+        Masculine Singular: "ēs" "ēī"   "ēī"   "em" "ē"    "ēs" N
+        Masculine Plural:   "ēs" "ērum" "ēbus" "ēs" "ēbus" "ēs" N
+        Common Singular:    "ēs" "ēī"   "ēī"   "em" "ē"    "ēs" N
+        Common Plural:      "ēs" "ērum" "ēbus" "ēs" "ēbus" "ēs" N
+        Neuter Singular:    N    N      N      N    N      N    N
+        Neuter Plural:      N    N      N      N    N      N    N
     ],
 };
 
-pub const FIRST_SECOND_ADJECTIVE_DECLENSION: NominalDeclension = NominalDeclension {
-    name: "First/Second Adjective Declension",
-    suffixes: suffixes! [
-        "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
-        "ae" "arum" "īs" "ās" "īs" "ae" "īs"
-        "us" "ī"    "o"  "um" "o"  "e"  "ī"
-        "ī"  "ōrum" "īs" "ōs" "īs" "ī"  "īs"
-        N    N      N    N    N    N    N
-        N    N      N    N    N    N    N
-        "um" "ī"    "o"  "um" "o"  "um" "ī"
-        "a"  "ōrum" "īs" "a" "īs"  "a"  "īs"
+pub const FIRST_SECOND_ADJECTIVE_DECLENSION: NominalDeclension = SECOND_DECLENSION
+    .with_name("First/Second Adjective Declension")
+    .with_gender(Gender::Feminine, &FIRST_DECLENSION)
+    .with_gender(Gender::Common, &EMPTY);
+
+/// Adjectives such as "fortis, forte", with one form for the masculine and feminine and another
+/// for the neuter. The third declension adjectives all decline as i-stems.
+pub const THIRD_TWO_TERMINATION_ADJECTIVE_DECLENSION: NominalDeclension = NominalDeclension {
+    name: "Third Adjective Declension (Two Terminations)",
+    suffixes: suffixes! [NominalCategories =>
+        Feminine Singular:  "is" "is"  "ī"    "em"      "ī"    "is" "ī"
+        Feminine Plural:    "ēs" "ium" "ibus" "ēs"|"īs" "ibus" "ēs" "ibus"
+        Masculine Singular: "is" "is"  "ī"    "em"      "ī"    "is" "ī"
+        Masculine Plural:   "ēs" "ium" "ibus" "ēs"|"īs" "ibus" "ēs" "ibus"
+        Common Singular:    "is" "is"  "ī"    "em"      "ī"    "is" "ī"
+        Common Plural:      "ēs" "ium" "ibus" "ēs"|"īs" "ibus" "ēs" "ibus"
+        Neuter Singular:    "e"  "is"  "ī"    "e"       "ī"    "e"  "ī"
+        Neuter Plural:      "ia" "ium" "ibus" "ia"      "ibus" "ia" "ibus"
     ],
 };
 
-pub const THIRD_ADJECTIVE_DECLENSION: NominalDeclension = NominalDeclension {
-    name: "Third Adjective Declension",
-    suffixes: suffixes! [
-        "is"   "is"   "ī"    "em" "e"    "is" "ī"
-        "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
-        "is"   "is"   "ī"    "em" "e"    "is" "ī"
-        "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
-        "is"   "is"   "ī"    "em" "e"    "is" "ī"
-        "ēs"   "um"   "ibus" "ēs" "ibus" "ēs" "ibus"
-        "e"    "is"   "ī"    "e"  "e"    "e"  "ī"
-        "a"    "um"   "ibus" "a"  "ibus" "a"  "ibus"
-    ],
+/// Adjectives such as "ācer, ācris, ācre", whose masculine nominative is the lemma.
+pub const THIRD_THREE_TERMINATION_ADJECTIVE_DECLENSION: NominalDeclension = THIRD_TWO_TERMINATION_ADJECTIVE_DECLENSION
+    .with_name("Third Adjective Declension (Three Terminations)")
+    .with_cell(NominalCategories(Gender::Masculine, Number::Singular, Case::Nominative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Masculine, Number::Singular, Case::Vocative), &[Suffix::Lemma]);
+
+#[deprecated(note = "use THIRD_THREE_TERMINATION_ADJECTIVE_DECLENSION, or the two or one termination declension")]
+pub const THIRD_ADJECTIVE_DECLENSION: NominalDeclension = THIRD_THREE_TERMINATION_ADJECTIVE_DECLENSION;
+
+/// Adjectives such as "ingēns, ingentis", whose nominative is the lemma in every gender.
+pub const THIRD_ONE_TERMINATION_ADJECTIVE_DECLENSION: NominalDeclension = THIRD_TWO_TERMINATION_ADJECTIVE_DECLENSION
+    .with_name("Third Adjective Declension (One Termination)")
+    .with_cell(NominalCategories(Gender::Feminine, Number::Singular, Case::Nominative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Feminine, Number::Singular, Case::Vocative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Masculine, Number::Singular, Case::Nominative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Masculine, Number::Singular, Case::Vocative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Common, Number::Singular, Case::Nominative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Common, Number::Singular, Case::Vocative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Neuter, Number::Singular, Case::Nominative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Neuter, Number::Singular, Case::Accusative), &[Suffix::Lemma])
+    .with_cell(NominalCategories(Gender::Neuter, Number::Singular, Case::Vocative), &[Suffix::Lemma]);
+
+/// Every declension of nouns and adjectives, nouns first.
+pub static DECLENSIONS: [NominalDeclension; 11] = [
+    FIRST_DECLENSION,
+    SECOND_DECLENSION,
+    THIRD_DECLENSION,
+    THIRD_I_STEM_DECLENSION,
+    THIRD_MIXED_DECLENSION,
+    FOURTH_DECLENSION,
+    FIFTH_DECLENSION,
+    FIRST_SECOND_ADJECTIVE_DECLENSION,
+    THIRD_TWO_TERMINATION_ADJECTIVE_DECLENSION,
+    THIRD_THREE_TERMINATION_ADJECTIVE_DECLENSION,
+    THIRD_ONE_TERMINATION_ADJECTIVE_DECLENSION,
+];
+
+/// A declension with no forms, for removing whole categories from composed declensions.
+const EMPTY: NominalDeclension = NominalDeclension {
+    name: "",
+    suffixes: [&[]; NominalCategories::COUNT],
 };
 
 #[cfg(test)]
@@ -134,13 +220,211 @@ mod tests {
     fn test_suffixes() {
         let poeta = crate::Word {
             inflection: FIRST_DECLENSION,
-            lemma: "poeta",
-            root: "poet",
+            lemma: "poeta".into(),
+            root: "poet".into(),
             regular: true,
             irregular_forms: vec![],
+            defective: vec![],
         };
 
         assert_eq!(poeta.inflect(NominalCategories(Gender::Masculine, Number::Plural, Case::Accusative)).unwrap(), "poetās");
         assert_eq!(NominalCategories(Gender::Common, Number::Plural, Case::Genitive).index(), 36);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_long_vowels() {
+        let rosa = crate::Word::new(FIRST_DECLENSION, "rosa", "ros");
+        let dominus = crate::Word::new(SECOND_DECLENSION, "dominus", "domin");
+        let bellum = crate::Word::new(SECOND_DECLENSION, "bellum", "bell");
+        let bonus = crate::Word::new(FIRST_SECOND_ADJECTIVE_DECLENSION, "bonus", "bon");
+
+        assert_eq!(rosa.inflect(NominalCategories(Gender::Feminine, Number::Plural, Case::Genitive)).unwrap(), "rosārum");
+        assert_eq!(dominus.inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Dative)).unwrap(), "dominō");
+        assert_eq!(dominus.inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Ablative)).unwrap(), "dominō");
+        assert_eq!(bellum.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Ablative)).unwrap(), "bellō");
+        assert_eq!(bonus.inflect(NominalCategories(Gender::Feminine, Number::Plural, Case::Genitive)).unwrap(), "bonārum");
+        assert_eq!(bonus.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Dative)).unwrap(), "bonō");
+    }
+
+    #[test]
+    fn test_analyze() {
+        let poeta = crate::Word::new(FIRST_DECLENSION, "poeta", "poet");
+        let bellum = crate::Word::new(SECOND_DECLENSION, "bellum", "bell");
+
+        let analyses = crate::analyze([&poeta], "poetīs");
+        assert_eq!(analyses.len(), 9);
+        assert!(analyses.contains(&("poeta", NominalCategories(Gender::Masculine, Number::Plural, Case::Dative))));
+        assert!(analyses.contains(&("poeta", NominalCategories(Gender::Masculine, Number::Plural, Case::Ablative))));
+
+        assert_eq!(
+            bellum.analyze("bella"),
+            vec![
+                NominalCategories(Gender::Neuter, Number::Plural, Case::Nominative),
+                NominalCategories(Gender::Neuter, Number::Plural, Case::Accusative),
+                NominalCategories(Gender::Neuter, Number::Plural, Case::Vocative),
+            ]
+        );
+        assert!(crate::analyze([&poeta, &bellum], "rosa").is_empty());
+    }
+
+    #[test]
+    fn test_builder() {
+        let rex = crate::Word::builder(THIRD_DECLENSION, "rēx", "rēg")
+            .stem_change()
+            .irregular(NominalCategories(Gender::Masculine, Number::Singular, Case::Nominative), Some("rēx".into()))
+            .build()
+            .unwrap();
+        assert_eq!(rex.inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Nominative)).unwrap(), "rēx");
+        assert_eq!(rex.inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Genitive)).unwrap(), "rēgis");
+
+        assert_eq!(
+            crate::Word::builder(THIRD_DECLENSION, "rēx", "rēg").build().err(),
+            Some(crate::WordError::RootNotPrefixOfLemma { lemma: "rēx".to_string(), root: "rēg".to_string() })
+        );
+        assert_eq!(
+            crate::Word::builder(FIRST_DECLENSION, "poeta", "poet")
+                .irregular(NominalCategories(Gender::Masculine, Number::Plural, Case::Locative), None)
+                .irregular(NominalCategories(Gender::Masculine, Number::Plural, Case::Locative), None)
+                .build()
+                .err(),
+            Some(crate::WordError::DuplicateIrregularForm { lemma: "poeta".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_lemma_forms() {
+        let corpus = crate::Word::builder(THIRD_DECLENSION, "corpus", "corpor").stem_change().build().unwrap();
+        let rex = crate::Word::builder(THIRD_DECLENSION, "rēx", "rēg").stem_change().build().unwrap();
+        let nomen = crate::Word::builder(THIRD_DECLENSION, "nōmen", "nōmin").stem_change().build().unwrap();
+
+        assert_eq!(corpus.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Nominative)).unwrap(), "corpus");
+        assert_eq!(corpus.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Accusative)).unwrap(), "corpus");
+        assert_eq!(corpus.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Genitive)).unwrap(), "corporis");
+        assert_eq!(rex.inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Nominative)).unwrap(), "rēx");
+        assert_eq!(rex.inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Vocative)).unwrap(), "rēx");
+        assert_eq!(rex.inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Accusative)).unwrap(), "rēgem");
+        assert_eq!(nomen.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Vocative)).unwrap(), "nōmen");
+        assert_eq!(nomen.inflect(NominalCategories(Gender::Neuter, Number::Plural, Case::Nominative)).unwrap(), "nōmina");
+    }
+
+    #[test]
+    fn test_variants() {
+        let filius = crate::Word::builder(SECOND_DECLENSION, "fīlius", "fīli")
+            .irregular(NominalCategories(Gender::Masculine, Number::Singular, Case::Genitive), Some("fīliī".into()))
+            .irregular(NominalCategories(Gender::Masculine, Number::Singular, Case::Genitive), Some("fīlī".into()))
+            .build()
+            .unwrap();
+        let genitive = NominalCategories(Gender::Masculine, Number::Singular, Case::Genitive);
+
+        assert_eq!(filius.inflect_all(genitive), vec!["fīliī", "fīlī"]);
+        assert_eq!(filius.inflect(genitive).unwrap(), "fīliī");
+        assert_eq!(filius.analyze("fīlī"), vec![genitive]);
+        assert!(crate::Word::builder(SECOND_DECLENSION, "fīlius", "fīli")
+            .irregular(genitive, Some("fīlī".into()))
+            .irregular(genitive, None)
+            .build()
+            .is_err());
+    }
+
+    const GENITIVE_PLURAL_COMMON: usize = NominalCategories(Gender::Common, Number::Plural, Case::Genitive).index();
+    const _: () = assert!(NominalCategories::COUNT == Gender::COUNT * Number::COUNT * Case::COUNT);
+
+    #[test]
+    fn test_all() {
+        assert_eq!(GENITIVE_PLURAL_COMMON, 36);
+        assert_eq!(NominalCategories::ALL[GENITIVE_PLURAL_COMMON], NominalCategories(Gender::Common, Number::Plural, Case::Genitive));
+        assert_eq!(Case::ALL.len(), Case::COUNT);
+        assert!(NominalCategories::iter_through_variants().eq(NominalCategories::ALL));
+    }
+
+    #[test]
+    fn test_from_index() {
+        for (i, categories) in NominalCategories::iter_through_variants().enumerate() {
+            assert_eq!(categories.index(), i);
+            assert_eq!(NominalCategories::from_index(i), Some(categories));
+        }
+        assert_eq!(NominalCategories::from_index(56), None);
+        assert_eq!(Case::from_index(3), Some(Case::Accusative));
+        assert_eq!(Case::from_index(7), None);
+    }
+
+    #[test]
+    fn test_defective() {
+        let castra = crate::Word::builder(SECOND_DECLENSION, "castra", "castr")
+            .defective(NominalCategories::is_singular)
+            .defective(NominalCategories::is_locative)
+            .build()
+            .unwrap();
+        let roma = crate::Word::builder(FIRST_DECLENSION, "Rōma", "Rōm").defective(NominalCategories::is_plural).build().unwrap();
+
+        assert_eq!(castra.inflect(NominalCategories(Gender::Neuter, Number::Plural, Case::Genitive)).unwrap(), "castrōrum");
+        assert_eq!(castra.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Nominative)), Err(InflectionError::Defective { lemma: "castra".to_string() }));
+        assert_eq!(castra.inflect(NominalCategories(Gender::Neuter, Number::Plural, Case::Locative)), Err(InflectionError::Defective { lemma: "castra".to_string() }));
+        assert_eq!(roma.inflect(NominalCategories(Gender::Feminine, Number::Singular, Case::Locative)).unwrap(), "Rōmae");
+        assert_eq!(roma.inflect(NominalCategories(Gender::Feminine, Number::Plural, Case::Nominative)), Err(InflectionError::Defective { lemma: "Rōma".to_string() }));
+        assert!(castra.analyze("castrum").is_empty());
+    }
+
+    #[test]
+    fn test_inflection_errors() {
+        let res = crate::Word::new(FIFTH_DECLENSION, "rēs", "r");
+        let vis = crate::Word::builder(THIRD_I_STEM_DECLENSION, "vīs", "vīr")
+            .stem_change()
+            .irregular(NominalCategories(Gender::Feminine, Number::Singular, Case::Dative), None)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            res.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Nominative)),
+            Err(InflectionError::NotInInflection)
+        );
+        assert_eq!(
+            vis.inflect(NominalCategories(Gender::Feminine, Number::Singular, Case::Dative)),
+            Err(InflectionError::IrregularlyMissing { lemma: "vīs".to_string() })
+        );
+        assert_eq!(
+            FIFTH_DECLENSION.suffix(NominalCategories(Gender::Neuter, Number::Plural, Case::Genitive)),
+            Err(InflectionError::NotInInflection)
+        );
+    }
+
+    #[test]
+    fn test_composition() {
+        let first_second: [&[Suffix]; NominalCategories::COUNT] = suffixes! [NominalCategories =>
+            Feminine Singular:  "a"  "ae"   "ae" "am" "ā"  "a"  "ae"
+            Feminine Plural:    "ae" "ārum" "īs" "ās" "īs" "ae" "īs"
+            Masculine Singular: "us" "ī"    "ō"  "um" "ō"  "e"  "ī"
+            Masculine Plural:   "ī"  "ōrum" "īs" "ōs" "īs" "ī"  "īs"
+            Common Singular:    N    N      N    N    N    N    N
+            Common Plural:      N    N      N    N    N    N    N
+            Neuter Singular:    "um" "ī"    "ō"  "um" "ō"  "um" "ī"
+            Neuter Plural:      "a"  "ōrum" "īs" "a"  "īs" "a"  "īs"
+        ];
+        let third: [&[Suffix]; NominalCategories::COUNT] = suffixes! [NominalCategories =>
+            Feminine Singular:  "is" "is"  "ī"    "em"      "ī"    "is" "ī"
+            Feminine Plural:    "ēs" "ium" "ibus" "ēs"|"īs" "ibus" "ēs" "ibus"
+            Masculine Singular: L    "is"  "ī"    "em"      "ī"    L    "ī"
+            Masculine Plural:   "ēs" "ium" "ibus" "ēs"|"īs" "ibus" "ēs" "ibus"
+            Common Singular:    "is" "is"  "ī"    "em"      "ī"    "is" "ī"
+            Common Plural:      "ēs" "ium" "ibus" "ēs"|"īs" "ibus" "ēs" "ibus"
+            Neuter Singular:    "e"  "is"  "ī"    "e"       "ī"    "e"  "ī"
+            Neuter Plural:      "ia" "ium" "ibus" "ia"      "ibus" "ia" "ibus"
+        ];
+
+        assert_eq!(FIRST_SECOND_ADJECTIVE_DECLENSION.name(), "First/Second Adjective Declension");
+        assert_eq!(FIRST_SECOND_ADJECTIVE_DECLENSION.suffixes, first_second);
+        #[allow(deprecated)]
+        let third_adjective = THIRD_ADJECTIVE_DECLENSION;
+        assert_eq!(third_adjective.suffixes, third);
+        assert_eq!(THIRD_DECLENSION.suffix(NominalCategories(Gender::Neuter, Number::Singular, Case::Nominative)), Ok(Suffix::Lemma));
+
+        let mixed = NominalCategories(Gender::Masculine, Number::Plural, Case::Accusative);
+        assert_eq!(THIRD_MIXED_DECLENSION.suffixes(mixed).collect::<Vec<_>>(), [Suffix::Ending("ēs"), Suffix::Ending("īs")]);
+        for NominalCategories(gender, number, case) in NominalCategories::ALL {
+            let changed = number == Number::Plural
+                && (case == Case::Genitive || (case == Case::Accusative && gender != Gender::Neuter));
+            let index = NominalCategories(gender, number, case).index();
+            assert_eq!(THIRD_MIXED_DECLENSION.suffixes[index] == THIRD_DECLENSION.suffixes[index], !changed);
+        }
+    }
+}
@@ -0,0 +1,468 @@
+use crate::latin::declension::*;
+use crate::normalize::strip_macrons;
+use crate::*;
+
+/// The declensions which an entry's genitive may point to, before the third declension is told
+/// apart by the nominative and gender.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Declension {
+    First,
+    Second,
+    Third,
+    ThirdMixed,
+    Fourth,
+    Fifth,
+}
+
+impl Declension {
+    fn table(self) -> NominalDeclension<'static> {
+        match self {
+            Declension::First => FIRST_DECLENSION,
+            Declension::Second => SECOND_DECLENSION,
+            Declension::Third => THIRD_DECLENSION,
+            Declension::ThirdMixed => THIRD_MIXED_DECLENSION,
+            Declension::Fourth => FOURTH_DECLENSION,
+            Declension::Fifth => FIFTH_DECLENSION,
+        }
+    }
+}
+
+/// The genitive singular endings which identify each declension, with and without macrons.
+const GENITIVE_ENDINGS: [(Declension, &[&str]); 5] = [
+    (Declension::First, &["ae"]),
+    (Declension::Second, &["ī", "i"]),
+    (Declension::Third, &["is"]),
+    (Declension::Fourth, &["ūs", "us"]),
+    (Declension::Fifth, &["ēī", "eī", "ēi", "ei"]),
+];
+
+/// The genitive plural endings which identify each declension in the entries of pluralia tantum,
+/// with and without macrons. Where several fit, the longest is taken, so that "castrōrum" is of the
+/// second declension rather than the third.
+const GENITIVE_PLURAL_ENDINGS: [(Declension, &[&str]); 6] = [
+    (Declension::First, &["ārum", "arum"]),
+    (Declension::Second, &["ōrum", "orum"]),
+    (Declension::ThirdMixed, &["ium"]),
+    (Declension::Third, &["um"]),
+    (Declension::Fourth, &["uum"]),
+    (Declension::Fifth, &["ērum", "erum"]),
+];
+
+/// Nouns which are not proper nouns but still have a locative.
+const COMMON_NOUNS_WITH_LOCATIVE: [&str; 4] = ["domus", "rūs", "humus", "mīlitia"];
+
+/// Whether `nominative` could be the nominative singular of a word in `declension`. This is only
+/// used to tell apart declensions whose genitives look alike, such as "diēī" and "dominī".
+fn nominative_fits(declension: Declension, nominative: &str) -> bool {
+    let endings: &[&str] = match declension {
+        Declension::First => &["a"],
+        Declension::Second => &["us", "um", "r"],
+        Declension::Fourth => &["us", "ū", "u"],
+        Declension::Fifth => &["ēs", "es"],
+        Declension::Third | Declension::ThirdMixed => return true,
+    };
+
+    endings.iter().any(|ending| nominative.ends_with(ending))
+}
+
+fn syllables(word: &str) -> usize {
+    let mut syllables = 0;
+    let mut previous_vowel = false;
+    for c in strip_macrons(word).to_lowercase().chars() {
+        let vowel = "aeiouy".contains(c);
+        if vowel && !previous_vowel {
+            syllables += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    syllables
+}
+
+/// Choose between the third declension tables for a noun with this nominative and root: pure
+/// i-stems for the neuters in -e, -al and -ar, mixed i-stems for nouns in -is and -ēs with as many
+/// syllables in the nominative as the genitive and for monosyllables whose root ends in two
+/// consonants, and consonant stems otherwise.
+///
+/// Pure i-stems in -is such as "turris" and exceptions such as "canis, canis" cannot be told apart
+/// from the others by their entries, so they are declined as mixed i-stems.
+fn third_declension(nominative: &str, genitive: &str, root: &str, gender: Gender) -> NominalDeclension<'static> {
+    let consonant = |c: char| !"aeiouyāēīōūȳ".contains(c);
+
+    if gender == Gender::Neuter {
+        if nominative.ends_with('e')
+            || (nominative.ends_with("al") && genitive.ends_with("ālis"))
+            || (nominative.ends_with("ar") && genitive.ends_with("āris"))
+        {
+            return THIRD_I_STEM_DECLENSION;
+        }
+        return THIRD_DECLENSION;
+    }
+
+    let parisyllabic = ["is", "ēs", "es"].iter().any(|ending| nominative.ends_with(ending))
+        && syllables(nominative) == syllables(genitive);
+    let two_consonants = root.chars().rev().take(2).filter(|&c| consonant(c)).count() == 2;
+    let monosyllable = syllables(nominative) == 1 && (nominative.ends_with('s') || nominative.ends_with('x'));
+    if parisyllabic || (monosyllable && two_consonants) {
+        THIRD_MIXED_DECLENSION
+    } else {
+        THIRD_DECLENSION
+    }
+}
+
+/// Parse the gender of an entry, which may be followed by "pl." for a plurale tantum or "sg." for a
+/// singulare tantum. Returns the gender and the number the word lacks, if any.
+fn parse_gender(gender: &str) -> Option<(Gender, Option<Number>)> {
+    let (gender, missing) = match gender.trim().rsplit_once(char::is_whitespace) {
+        Some((gender, "pl." | "pl")) => (gender, Some(Number::Singular)),
+        Some((gender, "sg." | "sg")) => (gender, Some(Number::Plural)),
+        _ => (gender, None),
+    };
+    let gender = match gender.trim().trim_end_matches('.') {
+        "m" => Gender::Masculine,
+        "f" => Gender::Feminine,
+        "n" => Gender::Neuter,
+        "c" | "m./f" | "f./m" | "m/f" | "f/m" => Gender::Common,
+        _ => return None,
+    };
+
+    Some((gender, missing))
+}
+
+/// The declension and root of a plurale tantum with this nominative and genitive plural.
+fn plural_declension<'e>(
+    nominative: &str,
+    genitive: &'e str,
+    gender: Gender,
+) -> Option<(NominalDeclension<'static>, &'e str)> {
+    let (declension, root) = GENITIVE_PLURAL_ENDINGS
+        .iter()
+        .flat_map(|(declension, endings)| endings.iter().map(move |ending| (*declension, ending)))
+        .filter_map(|(declension, ending)| Some((declension, ending, genitive.strip_suffix(ending)?)))
+        .filter(|(_, _, root)| !root.is_empty())
+        .max_by_key(|(_, ending, _)| ending.chars().count())
+        .map(|(declension, _, root)| (declension, root))?;
+
+    match declension {
+        Declension::ThirdMixed if gender == Gender::Neuter || nominative.ends_with("ia") => {
+            Some((THIRD_I_STEM_DECLENSION, root))
+        }
+        _ => Some((declension.table(), root)),
+    }
+}
+
+/// Parse a dictionary headword such as "poeta, poetae, m." (nominative, genitive, gender) into a
+/// word and its gender. The declension is chosen by the genitive singular, which also gives the
+/// root, and in the third declension by the nominative and gender as well.
+///
+/// Where the declension does not give the nominative as the lemma and it is not the root plus the
+/// declension's ending, as in "ager, agrī", the nominative and vocative singular are given as
+/// irregular forms.
+///
+/// A plurale tantum is given with its nominative and genitive plural and "pl." after its gender, as
+/// in "castra, castrōrum, n. pl.", and a singulare tantum with "sg." after its gender. Only proper
+/// nouns, taken to be the names of places, and a few common nouns such as "domus" have a locative.
+pub fn parse_entry(entry: &str) -> Result<(Word<'_, NominalDeclension<'_>>, Gender), EntryError> {
+    let parts = entry.split(',').map(str::trim).collect::<Vec<_>>();
+    let (nominative, genitive, gender) = match parts[..] {
+        [nominative, genitive, gender] if !nominative.is_empty() && !genitive.is_empty() => {
+            (nominative, genitive, gender)
+        }
+        _ => return Err(EntryError::Malformed { entry: entry.to_string() }),
+    };
+    let (gender, missing_number) =
+        parse_gender(gender).ok_or_else(|| EntryError::UnknownGender { gender: gender.to_string() })?;
+
+    let (declension, root) = if missing_number == Some(Number::Singular) {
+        plural_declension(nominative, genitive, gender)
+            .ok_or_else(|| EntryError::UnrecognizedGenitive { genitive: genitive.to_string() })?
+    } else {
+        singular_declension(entry, nominative, genitive, gender)?
+    };
+    let number = match missing_number {
+        Some(Number::Singular) => Number::Plural,
+        _ => Number::Singular,
+    };
+
+    let name = declension.name();
+    let nominative_categories = NominalCategories(gender, number, Case::Nominative);
+    let regular_nominative = declension
+        .inflect(nominative, root, nominative_categories)
+        .map_err(|_| EntryError::GenderNotInDeclension { gender, declension: name })?;
+
+    let mut builder = Word::builder(declension, nominative, root);
+    if !nominative.starts_with(root) {
+        builder = builder.stem_change();
+    }
+    if regular_nominative != nominative {
+        for case in [Case::Nominative, Case::Vocative] {
+            builder = builder.irregular(NominalCategories(gender, number, case), Some(nominative.into()));
+        }
+    }
+    if let Some(missing_number) = missing_number {
+        builder = builder.defective(move |categories| categories.1 == missing_number);
+    }
+    let proper_noun = nominative.starts_with(char::is_uppercase);
+    if !proper_noun && !COMMON_NOUNS_WITH_LOCATIVE.contains(&nominative) {
+        builder = builder.defective(NominalCategories::is_locative);
+    }
+
+    Ok((builder.build()?, gender))
+}
+
+/// The declension and root of a noun with this nominative and genitive singular.
+fn singular_declension<'e>(
+    entry: &str,
+    nominative: &str,
+    genitive: &'e str,
+    gender: Gender,
+) -> Result<(NominalDeclension<'static>, &'e str), EntryError> {
+    let candidates = GENITIVE_ENDINGS
+        .iter()
+        .filter_map(|(declension, endings)| {
+            let root = endings.iter().find_map(|ending| genitive.strip_suffix(ending))?;
+            (!root.is_empty()).then_some((*declension, root))
+        })
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return Err(EntryError::UnrecognizedGenitive { genitive: genitive.to_string() });
+    }
+
+    let fitting = candidates
+        .iter()
+        .filter(|(declension, _)| nominative_fits(*declension, nominative))
+        .collect::<Vec<_>>();
+    let (declension, root) = match fitting[..] {
+        [&candidate] => candidate,
+        [] => return Err(EntryError::UnrecognizedGenitive { genitive: genitive.to_string() }),
+        _ => {
+            return Err(EntryError::Ambiguous {
+                entry: entry.to_string(),
+                declensions: fitting.iter().map(|(declension, _)| declension.table().name()).collect(),
+            })
+        }
+    };
+    let declension = match declension {
+        Declension::Third => third_declension(nominative, genitive, root, gender),
+        _ => declension.table(),
+    };
+
+    Ok((declension, root))
+}
+
+/// Parse the dictionary headword of an adjective, which is one of:
+/// - the masculine, feminine and neuter nominatives of a first/second declension adjective, as in
+///   "bonus, bona, bonum" or "pulcher, pulchra, pulchrum";
+/// - the masculine, feminine and neuter nominatives of a third declension adjective of three
+///   terminations, as in "ācer, ācris, ācre";
+/// - the masculine and feminine, then neuter nominatives of a third declension adjective of two
+///   terminations, as in "fortis, forte";
+/// - the nominative and genitive of a third declension adjective of one termination, as in
+///   "ingēns, ingentis".
+///
+/// The lemma is the masculine nominative singular.
+pub fn parse_adjective_entry(entry: &str) -> Result<Word<'_, NominalDeclension<'_>>, EntryError> {
+    let parts = entry.split(',').map(str::trim).collect::<Vec<_>>();
+    let unrecognized = || EntryError::UnrecognizedAdjective { entry: entry.to_string() };
+    if parts.iter().any(|part| part.is_empty()) {
+        return Err(unrecognized());
+    }
+
+    let (declension, lemma, root) = match parts[..] {
+        [masculine, feminine, neuter] if feminine.ends_with('a') && neuter.ends_with("um") => {
+            (FIRST_SECOND_ADJECTIVE_DECLENSION, masculine, &neuter[..neuter.len() - "um".len()])
+        }
+        [masculine, feminine, neuter] if feminine.ends_with("is") && neuter.ends_with('e') => {
+            (THIRD_THREE_TERMINATION_ADJECTIVE_DECLENSION, masculine, &neuter[..neuter.len() - "e".len()])
+        }
+        [masculine, neuter] if masculine.ends_with("is") && neuter.ends_with('e') => {
+            (THIRD_TWO_TERMINATION_ADJECTIVE_DECLENSION, masculine, &neuter[..neuter.len() - "e".len()])
+        }
+        [nominative, genitive] if genitive.ends_with("is") => {
+            (THIRD_ONE_TERMINATION_ADJECTIVE_DECLENSION, nominative, &genitive[..genitive.len() - "is".len()])
+        }
+        _ => return Err(unrecognized()),
+    };
+    if root.is_empty() {
+        return Err(unrecognized());
+    }
+
+    let mut builder = Word::builder(declension, lemma, root);
+    if !lemma.starts_with(root) {
+        builder = builder.stem_change();
+    }
+    let masculine = |case| NominalCategories(Gender::Masculine, Number::Singular, case);
+    if declension.inflect(lemma, root, masculine(Case::Nominative)).as_deref() != Ok(lemma) {
+        for case in [Case::Nominative, Case::Vocative] {
+            builder = builder.irregular(masculine(case), Some(lemma.into()));
+        }
+    }
+
+    Ok(builder.build()?)
+}
+
+/// The reasons a dictionary entry may fail to parse.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EntryError {
+    /// The entry is not of the form "nominative, genitive, gender".
+    Malformed { entry: String },
+    UnknownGender { gender: String },
+    /// The genitive singular does not end in the genitive of any declension which fits the
+    /// nominative.
+    UnrecognizedGenitive { genitive: String },
+    /// More than one declension fits the entry: its genitive singular ends in the genitive of each,
+    /// and its nominative in a nominative of each. No entry is ambiguous with the declensions as
+    /// they are, since those whose nominatives overlap, such as the second and fourth in "-us",
+    /// have genitives which do not, but another declension recognized by its genitive may make
+    /// some so.
+    Ambiguous { entry: String, declensions: Vec<&'static str> },
+    /// The declension has no forms in the entry's gender, e.g. a neuter of the first declension.
+    GenderNotInDeclension { gender: Gender, declension: &'static str },
+    /// The entry is not of any of the forms accepted by `parse_adjective_entry`.
+    UnrecognizedAdjective { entry: String },
+    Word(WordError),
+}
+
+impl From<WordError> for EntryError {
+    fn from(error: WordError) -> Self {
+        EntryError::Word(error)
+    }
+}
+
+impl std::fmt::Display for EntryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            EntryError::Malformed { entry } => {
+                write!(f, "\"{}\" is not of the form \"nominative, genitive, gender\"", entry)
+            }
+            EntryError::UnknownGender { gender } => write!(f, "unknown gender \"{}\"", gender),
+            EntryError::UnrecognizedGenitive { genitive } => {
+                write!(f, "\"{}\" is not the genitive singular of any declension", genitive)
+            }
+            EntryError::Ambiguous { entry, declensions } => {
+                write!(f, "\"{}\" could belong to any of: {}", entry, declensions.join(", "))
+            }
+            EntryError::GenderNotInDeclension { gender, declension } => {
+                write!(f, "the {} has no {:?} forms", declension, gender)
+            }
+            EntryError::UnrecognizedAdjective { entry } => {
+                write!(f, "\"{}\" is not the headword of a first/second or third declension adjective", entry)
+            }
+            EntryError::Word(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for EntryError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entry() {
+        let (poeta, gender) = parse_entry("poeta, poetae, m.").unwrap();
+        assert_eq!(gender, Gender::Masculine);
+        assert_eq!(poeta.inflect(NominalCategories(gender, Number::Plural, Case::Accusative)).unwrap(), "poetās");
+
+        let (dies, gender) = parse_entry("diēs, diēī, m.").unwrap();
+        assert_eq!(dies.inflect(NominalCategories(gender, Number::Plural, Case::Genitive)).unwrap(), "diērum");
+
+        let (ager, gender) = parse_entry("ager, agrī, m.").unwrap();
+        assert_eq!(ager.inflect(NominalCategories(gender, Number::Singular, Case::Nominative)).unwrap(), "ager");
+        assert_eq!(ager.inflect(NominalCategories(gender, Number::Singular, Case::Accusative)).unwrap(), "agrum");
+
+        let (rex, gender) = parse_entry("rēx, rēgis, m.").unwrap();
+        assert_eq!(rex.inflect(NominalCategories(gender, Number::Singular, Case::Nominative)).unwrap(), "rēx");
+        assert_eq!(rex.inflect(NominalCategories(gender, Number::Singular, Case::Accusative)).unwrap(), "rēgem");
+
+        let (corpus, gender) = parse_entry("corpus, corporis, n.").unwrap();
+        assert_eq!(corpus.inflect(NominalCategories(gender, Number::Singular, Case::Accusative)).unwrap(), "corpus");
+        assert_eq!(corpus.inflect(NominalCategories(gender, Number::Plural, Case::Nominative)).unwrap(), "corpora");
+
+        assert_eq!(parse_entry("cīvis, cīvis, m./f.").unwrap().1, Gender::Common);
+    }
+
+    #[test]
+    fn test_third_declension_stems() {
+        let genitive_plural = |entry| {
+            let (word, gender) = parse_entry(entry).unwrap();
+            word.inflect(NominalCategories(gender, Number::Plural, Case::Genitive)).unwrap()
+        };
+
+        assert_eq!(genitive_plural("rēx, rēgis, m."), "rēgum");
+        assert_eq!(genitive_plural("mīles, mīlitis, m."), "mīlitum");
+        assert_eq!(genitive_plural("cīvis, cīvis, c."), "cīvium");
+        assert_eq!(genitive_plural("nūbēs, nūbis, f."), "nūbium");
+        assert_eq!(genitive_plural("urbs, urbis, f."), "urbium");
+        assert_eq!(genitive_plural("nox, noctis, f."), "noctium");
+        assert_eq!(genitive_plural("lēx, lēgis, f."), "lēgum");
+        assert_eq!(genitive_plural("corpus, corporis, n."), "corporum");
+
+        let (mare, gender) = parse_entry("mare, maris, n.").unwrap();
+        assert_eq!(mare.inflect_all(NominalCategories(gender, Number::Plural, Case::Nominative)), ["maria"]);
+        assert_eq!(mare.inflect(NominalCategories(gender, Number::Singular, Case::Ablative)).unwrap(), "marī");
+        let (animal, gender) = parse_entry("animal, animālis, n.").unwrap();
+        assert_eq!(animal.inflect(NominalCategories(gender, Number::Singular, Case::Nominative)).unwrap(), "animal");
+        assert_eq!(animal.inflect(NominalCategories(gender, Number::Plural, Case::Genitive)).unwrap(), "animālium");
+    }
+
+    #[test]
+    fn test_defective_entries() {
+        let (poeta, gender) = parse_entry("poeta, poetae, m.").unwrap();
+        assert_eq!(poeta.inflect(NominalCategories(gender, Number::Singular, Case::Locative)), Err(InflectionError::Defective { lemma: "poeta".to_string() }));
+        let (roma, gender) = parse_entry("Rōma, Rōmae, f.").unwrap();
+        assert_eq!(roma.inflect(NominalCategories(gender, Number::Singular, Case::Locative)).unwrap(), "Rōmae");
+
+        let (castra, gender) = parse_entry("castra, castrōrum, n. pl.").unwrap();
+        assert_eq!(castra.inflect(NominalCategories(gender, Number::Plural, Case::Dative)).unwrap(), "castrīs");
+        assert_eq!(castra.inflect(NominalCategories(gender, Number::Singular, Case::Nominative)), Err(InflectionError::Defective { lemma: "castra".to_string() }));
+        let (divitiae, gender) = parse_entry("dīvitiae, dīvitiārum, f. pl.").unwrap();
+        assert_eq!(divitiae.inflect(NominalCategories(gender, Number::Plural, Case::Accusative)).unwrap(), "dīvitiās");
+        assert_eq!(divitiae.inflect(NominalCategories(gender, Number::Singular, Case::Genitive)), Err(InflectionError::Defective { lemma: "dīvitiae".to_string() }));
+        let (moenia, gender) = parse_entry("moenia, moenium, n. pl.").unwrap();
+        assert_eq!(moenia.inflect(NominalCategories(gender, Number::Plural, Case::Nominative)).unwrap(), "moenia");
+        assert_eq!(moenia.inflect(NominalCategories(gender, Number::Plural, Case::Ablative)).unwrap(), "moenibus");
+
+        let (aurum, gender) = parse_entry("aurum, aurī, n. sg.").unwrap();
+        assert_eq!(aurum.inflect(NominalCategories(gender, Number::Plural, Case::Nominative)), Err(InflectionError::Defective { lemma: "aurum".to_string() }));
+    }
+
+    #[test]
+    fn test_parse_adjective_entry() {
+        let form = |entry, gender, number, case| {
+            parse_adjective_entry(entry).unwrap().inflect(NominalCategories(gender, number, case)).unwrap()
+        };
+
+        assert_eq!(form("bonus, bona, bonum", Gender::Feminine, Number::Plural, Case::Genitive), "bonārum");
+        assert_eq!(form("pulcher, pulchra, pulchrum", Gender::Masculine, Number::Singular, Case::Nominative), "pulcher");
+        assert_eq!(form("pulcher, pulchra, pulchrum", Gender::Masculine, Number::Singular, Case::Genitive), "pulchrī");
+        assert_eq!(form("ācer, ācris, ācre", Gender::Masculine, Number::Singular, Case::Nominative), "ācer");
+        assert_eq!(form("ācer, ācris, ācre", Gender::Feminine, Number::Singular, Case::Nominative), "ācris");
+        assert_eq!(form("ācer, ācris, ācre", Gender::Neuter, Number::Plural, Case::Nominative), "ācria");
+        assert_eq!(form("fortis, forte", Gender::Masculine, Number::Singular, Case::Ablative), "fortī");
+        assert_eq!(form("fortis, forte", Gender::Neuter, Number::Singular, Case::Nominative), "forte");
+        assert_eq!(form("ingēns, ingentis", Gender::Neuter, Number::Singular, Case::Accusative), "ingēns");
+        assert_eq!(form("ingēns, ingentis", Gender::Feminine, Number::Singular, Case::Accusative), "ingentem");
+        assert_eq!(form("ingēns, ingentis", Gender::Masculine, Number::Plural, Case::Genitive), "ingentium");
+
+        assert_eq!(
+            parse_adjective_entry("bonus, bonī").err(),
+            Some(EntryError::UnrecognizedAdjective { entry: "bonus, bonī".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_parse_entry_errors() {
+        assert_eq!(parse_entry("poeta, m.").err(), Some(EntryError::Malformed { entry: "poeta, m.".to_string() }));
+        assert_eq!(parse_entry("poeta, poetae, x.").err(), Some(EntryError::UnknownGender { gender: "x.".to_string() }));
+        assert_eq!(
+            parse_entry("poeta, poetam, m.").err(),
+            Some(EntryError::UnrecognizedGenitive { genitive: "poetam".to_string() })
+        );
+        assert_eq!(
+            parse_entry("rosa, rosae, n.").err(),
+            Some(EntryError::GenderNotInDeclension { gender: Gender::Neuter, declension: "First Declension" })
+        );
+    }
+}
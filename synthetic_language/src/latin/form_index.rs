@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use crate::latin::conjugation::VerbCategories;
+use crate::latin::declension::NominalCategories;
+use crate::latin::lexicon::*;
+use crate::normalize::{nfc, strip_macrons};
+use crate::*;
+
+/// One analysis of a form: the id of its entry in the lexicon and the index of its categories,
+/// which are `NominalCategories` for nouns and adjectives and `VerbCategories` for verbs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormAnalysis {
+    pub id: usize,
+    pub categories: usize,
+}
+
+/// Every form of every word in a `Lexicon`, mapped back to its analyses, so that a form is
+/// analyzed by a single lookup rather than by inflecting the whole lexicon. A noun is indexed only
+/// in its own gender. The ids are only meaningful for the lexicon the index was built from.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormIndex {
+    ignore_macrons: bool,
+    forms: BTreeMap<String, Vec<FormAnalysis>>,
+}
+
+impl FormIndex {
+    /// Index every form of every entry in `lexicon`, as written.
+    pub fn new(lexicon: &Lexicon) -> Self {
+        FormIndex::build(lexicon, false)
+    }
+
+    /// Index every form of every entry in `lexicon` without its macrons, so that "rosarum" is
+    /// analyzed as "rosārum" is.
+    pub fn ignoring_macrons(lexicon: &Lexicon) -> Self {
+        FormIndex::build(lexicon, true)
+    }
+
+    fn build(lexicon: &Lexicon, ignore_macrons: bool) -> Self {
+        let mut index = FormIndex { ignore_macrons, forms: BTreeMap::new() };
+
+        for (id, entry) in lexicon.entries() {
+            match entry.lexeme() {
+                Lexeme::Noun { word, gender } => {
+                    for categories in NominalCategories::iter_through_variants().filter(|categories| categories.0 == *gender) {
+                        index.insert(word.inflect_all(categories), id, categories.index());
+                    }
+                }
+                Lexeme::Adjective { word } => {
+                    for categories in NominalCategories::iter_through_variants() {
+                        index.insert(word.inflect_all(categories), id, categories.index());
+                    }
+                }
+                Lexeme::Verb { verb } => {
+                    for categories in VerbCategories::iter_through_variants() {
+                        index.insert(verb.inflect_all(categories), id, categories.index());
+                    }
+                }
+            }
+        }
+
+        index
+    }
+
+    fn insert(&mut self, forms: Vec<String>, id: usize, categories: usize) {
+        let analysis = FormAnalysis { id, categories };
+        for form in forms {
+            let analyses = self.forms.entry(self.key(&form)).or_default();
+            if !analyses.contains(&analysis) {
+                analyses.push(analysis);
+            }
+        }
+    }
+
+    fn key(&self, form: &str) -> String {
+        if self.ignore_macrons {
+            strip_macrons(form)
+        } else {
+            nfc(form)
+        }
+    }
+
+    /// Every analysis of `form`, in the order of the lexicon.
+    pub fn analyze(&self, form: &str) -> &[FormAnalysis] {
+        self.forms.get(&self.key(form)).map_or(&[], Vec::as_slice)
+    }
+
+    /// The number of distinct forms indexed.
+    pub fn len(&self) -> usize {
+        self.forms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.forms.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latin::conjugation::{self, Aspect, Mood, Person, Tense, Voice};
+    use crate::latin::declension::*;
+    use crate::latin::dictionary::*;
+    use crate::latin::verb::Verb;
+
+    fn lexicon() -> Lexicon<'static> {
+        let mut lexicon = Lexicon::new();
+        let (rosa, gender) = parse_entry("rosa, rosae, f.").unwrap();
+        lexicon.insert(LexiconEntry::noun(rosa, gender));
+        let bonus = parse_adjective_entry("bonus, bona, bonum").unwrap();
+        lexicon.insert(LexiconEntry::adjective(bonus));
+        let amo = Verb::from_principal_parts("amō, amāre, amāvī, amātum").unwrap();
+        lexicon.insert(LexiconEntry::verb(amo));
+        lexicon
+    }
+
+    #[test]
+    fn test_analyze() {
+        let lexicon = lexicon();
+        let index = FormIndex::new(&lexicon);
+        let nominal = |gender, number, case| NominalCategories(gender, number, case).index();
+
+        assert_eq!(
+            index.analyze("rosārum"),
+            [FormAnalysis { id: 0, categories: nominal(Gender::Feminine, Number::Plural, Case::Genitive) }]
+        );
+        assert_eq!(index.analyze("rosa\u{304}rum"), index.analyze("rosārum"));
+        assert!(index.analyze("rosarum").is_empty());
+        assert!(index.analyze("rosārum").iter().all(|analysis| lexicon.get(analysis.id).unwrap().lemma() == "rosa"));
+
+        let bonae = index.analyze("bonae").iter().map(|analysis| analysis.categories).collect::<Vec<_>>();
+        assert!(bonae.contains(&nominal(Gender::Feminine, Number::Singular, Case::Genitive)));
+        assert!(bonae.contains(&nominal(Gender::Feminine, Number::Plural, Case::Nominative)));
+        assert!(index.analyze("rosō").is_empty());
+
+        let amat = VerbCategories(Voice::Active, Mood::Indicative, Aspect::Imperfective, Tense::Present, conjugation::Number::Singular, Person::Third);
+        assert_eq!(index.analyze("amat"), [FormAnalysis { id: 2, categories: amat.index() }]);
+    }
+
+    #[test]
+    fn test_ignoring_macrons() {
+        let lexicon = lexicon();
+        let index = FormIndex::ignoring_macrons(&lexicon);
+
+        assert_eq!(index.analyze("rosarum"), index.analyze("rosārum"));
+        assert_eq!(index.analyze("rosarum").len(), 1);
+        // "rosā" and "rosa" are now the same form.
+        assert_eq!(index.analyze("rosa").len(), 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        let index = FormIndex::ignoring_macrons(&lexicon());
+        let json = serde_json::to_string(&index).unwrap();
+
+        assert_eq!(serde_json::from_str::<FormIndex>(&json).unwrap(), index);
+    }
+}
@@ -0,0 +1,245 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::latin::conjugation::*;
+use crate::latin::declension::*;
+use crate::latin::verb::Verb;
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PartOfSpeech {
+    Noun,
+    Adjective,
+    Verb,
+}
+
+/// A word of the lexicon with what is needed to inflect it.
+pub enum Lexeme<'a> {
+    Noun { word: Word<'a, NominalDeclension<'a>>, gender: Gender },
+    /// An adjective, declined in every gender.
+    Adjective { word: Word<'a, NominalDeclension<'a>> },
+    /// A verb, boxed as its three stems make it several times the size of a noun.
+    Verb { verb: Box<Verb<'a>> },
+}
+
+/// A word of the lexicon together with its glosses and tags. Tags are free-form labels such as
+/// "chapter 3" or "core 500", by which a word list may be filtered.
+pub struct LexiconEntry<'a> {
+    lexeme: Lexeme<'a>,
+    glosses: Vec<Cow<'a, str>>,
+    tags: Vec<Cow<'a, str>>,
+}
+
+impl<'a> LexiconEntry<'a> {
+    pub fn noun(word: Word<'a, NominalDeclension<'a>>, gender: Gender) -> Self {
+        LexiconEntry::new(Lexeme::Noun { word, gender })
+    }
+
+    pub fn adjective(word: Word<'a, NominalDeclension<'a>>) -> Self {
+        LexiconEntry::new(Lexeme::Adjective { word })
+    }
+
+    pub fn verb(verb: Verb<'a>) -> Self {
+        LexiconEntry::new(Lexeme::Verb { verb: Box::new(verb) })
+    }
+
+    fn new(lexeme: Lexeme<'a>) -> Self {
+        LexiconEntry { lexeme, glosses: Vec::new(), tags: Vec::new() }
+    }
+
+    /// Add a meaning of the word, in order of importance.
+    pub fn gloss(mut self, gloss: impl Into<Cow<'a, str>>) -> Self {
+        self.glosses.push(gloss.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<Cow<'a, str>>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn lexeme(&self) -> &Lexeme<'a> {
+        &self.lexeme
+    }
+
+    pub fn lemma(&self) -> &str {
+        match &self.lexeme {
+            Lexeme::Noun { word, .. } | Lexeme::Adjective { word } => word.lemma(),
+            Lexeme::Verb { verb } => verb.lemma(),
+        }
+    }
+
+    pub fn part_of_speech(&self) -> PartOfSpeech {
+        match self.lexeme {
+            Lexeme::Noun { .. } => PartOfSpeech::Noun,
+            Lexeme::Adjective { .. } => PartOfSpeech::Adjective,
+            Lexeme::Verb { .. } => PartOfSpeech::Verb,
+        }
+    }
+
+    /// The gender of a noun. Adjectives and verbs have none.
+    pub fn gender(&self) -> Option<Gender> {
+        match self.lexeme {
+            Lexeme::Noun { gender, .. } => Some(gender),
+            _ => None,
+        }
+    }
+
+    /// The declension of a noun or adjective.
+    pub fn declension(&self) -> Option<&NominalDeclension<'a>> {
+        match &self.lexeme {
+            Lexeme::Noun { word, .. } | Lexeme::Adjective { word } => Some(word.inflection()),
+            Lexeme::Verb { .. } => None,
+        }
+    }
+
+    /// The conjugation of a verb.
+    pub fn conjugation(&self) -> Option<&VerbConjugation<'a>> {
+        match &self.lexeme {
+            Lexeme::Verb { verb } => Some(verb.conjugation()),
+            _ => None,
+        }
+    }
+
+    pub fn glosses(&self) -> impl Iterator<Item = &str> {
+        self.glosses.iter().map(|gloss| gloss.as_ref())
+    }
+
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.tags.iter().map(|tag| tag.as_ref())
+    }
+}
+
+/// A vocabulary of nouns, adjectives and verbs. Each entry is known by its id, the order in which
+/// it was added. Several entries may share a lemma, such as a noun and an adjective spelled alike.
+#[derive(Default)]
+pub struct Lexicon<'a> {
+    entries: Vec<LexiconEntry<'a>>,
+    by_lemma: HashMap<String, Vec<usize>>,
+    by_tag: HashMap<String, Vec<usize>>,
+}
+
+impl<'a> Lexicon<'a> {
+    pub fn new() -> Self {
+        Lexicon::default()
+    }
+
+    /// Add `entry`, returning its id.
+    pub fn insert(&mut self, entry: LexiconEntry<'a>) -> usize {
+        let id = self.entries.len();
+        self.by_lemma.entry(entry.lemma().to_string()).or_default().push(id);
+        for tag in entry.tags() {
+            self.by_tag.entry(tag.to_string()).or_default().push(id);
+        }
+        self.entries.push(entry);
+        id
+    }
+
+    pub fn get(&self, id: usize) -> Option<&LexiconEntry<'a>> {
+        self.entries.get(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every entry with its id, in the order they were added.
+    pub fn entries(&self) -> impl Iterator<Item = (usize, &LexiconEntry<'a>)> {
+        self.entries.iter().enumerate()
+    }
+
+    /// Every entry whose lemma is `lemma`.
+    pub fn lookup(&self, lemma: &str) -> impl Iterator<Item = &LexiconEntry<'a>> {
+        self.ids(&self.by_lemma, lemma)
+    }
+
+    /// Every entry tagged `tag`.
+    pub fn with_tag(&self, tag: &str) -> impl Iterator<Item = &LexiconEntry<'a>> {
+        self.ids(&self.by_tag, tag)
+    }
+
+    /// Every noun and adjective declined in `declension`, which must have the same name and suffixes,
+    /// so that `FIRST_SECOND_ADJECTIVE_DECLENSION` is not the same as the `SECOND_DECLENSION` it is
+    /// built on, nor is a changed table which kept its name.
+    pub fn with_declension<'l>(&'l self, declension: &'l NominalDeclension) -> impl Iterator<Item = &'l LexiconEntry<'a>> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.declension().is_some_and(|d| d == declension))
+    }
+
+    /// Every verb conjugated in `conjugation`.
+    pub fn with_conjugation<'l>(&'l self, conjugation: &'l VerbConjugation) -> impl Iterator<Item = &'l LexiconEntry<'a>> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.conjugation().is_some_and(|c| c == conjugation))
+    }
+
+    pub fn with_part_of_speech(&self, part_of_speech: PartOfSpeech) -> impl Iterator<Item = &LexiconEntry<'a>> {
+        self.entries.iter().filter(move |entry| entry.part_of_speech() == part_of_speech)
+    }
+
+    fn ids<'l>(&'l self, index: &'l HashMap<String, Vec<usize>>, key: &str) -> impl Iterator<Item = &'l LexiconEntry<'a>> {
+        index.get(key).into_iter().flatten().map(|&id| &self.entries[id])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latin::dictionary::*;
+
+    fn lexicon() -> Lexicon<'static> {
+        let mut lexicon = Lexicon::new();
+        let (rosa, gender) = parse_entry("rosa, rosae, f.").unwrap();
+        lexicon.insert(LexiconEntry::noun(rosa, gender).gloss("rose").tag("chapter 1"));
+        let (manus, gender) = parse_entry("manus, manūs, f.").unwrap();
+        lexicon.insert(LexiconEntry::noun(manus, gender).gloss("hand").gloss("band").tag("chapter 4"));
+        let (cornu, gender) = parse_entry("cornū, cornūs, n.").unwrap();
+        lexicon.insert(LexiconEntry::noun(cornu, gender).gloss("horn").tag("chapter 4"));
+        let bonus = parse_adjective_entry("bonus, bona, bonum").unwrap();
+        lexicon.insert(LexiconEntry::adjective(bonus).gloss("good").tag("chapter 1"));
+        let amo = Verb::from_principal_parts("amō, amāre, amāvī, amātum").unwrap();
+        lexicon.insert(LexiconEntry::verb(amo).gloss("love").tag("chapter 1"));
+        lexicon
+    }
+
+    #[test]
+    fn test_lookup() {
+        let lexicon = lexicon();
+
+        let manus = lexicon.lookup("manus").next().unwrap();
+        assert_eq!(manus.part_of_speech(), PartOfSpeech::Noun);
+        assert_eq!(manus.gender(), Some(Gender::Feminine));
+        assert_eq!(manus.declension().unwrap().name(), "Fourth Declension");
+        assert_eq!(manus.glosses().collect::<Vec<_>>(), ["hand", "band"]);
+        assert!(lexicon.lookup("manūs").next().is_none());
+
+        let amo = lexicon.lookup("amō").next().unwrap();
+        assert_eq!(amo.part_of_speech(), PartOfSpeech::Verb);
+        assert_eq!(amo.conjugation().unwrap().name(), FIRST_CONJUGATION.name());
+        assert_eq!(amo.declension().map(|d| d.name()), None);
+        assert_eq!(lexicon.len(), 5);
+        assert_eq!(lexicon.get(0).unwrap().lemma(), "rosa");
+    }
+
+    #[test]
+    fn test_filters() {
+        let lexicon = lexicon();
+        let lemmas = |entries: Vec<&LexiconEntry>| entries.iter().map(|entry| entry.lemma().to_string()).collect::<Vec<_>>();
+
+        assert_eq!(lemmas(lexicon.with_declension(&FOURTH_DECLENSION).collect()), ["manus", "cornū"]);
+        assert_eq!(lemmas(lexicon.with_declension(&SECOND_DECLENSION).collect()), Vec::<String>::new());
+        assert_eq!(lemmas(lexicon.with_declension(&FIRST_SECOND_ADJECTIVE_DECLENSION).collect()), ["bonus"]);
+        let changed = FOURTH_DECLENSION.with_gender(Gender::Neuter, &FIRST_DECLENSION);
+        assert_eq!(changed.name(), FOURTH_DECLENSION.name());
+        assert_eq!(lemmas(lexicon.with_declension(&changed).collect()), Vec::<String>::new());
+        assert_eq!(lemmas(lexicon.with_conjugation(&FIRST_CONJUGATION).collect()), ["amō"]);
+        assert_eq!(lemmas(lexicon.with_tag("chapter 1").collect()), ["rosa", "bonus", "amō"]);
+        assert_eq!(lemmas(lexicon.with_tag("chapter 9").collect()), Vec::<String>::new());
+        assert_eq!(lemmas(lexicon.with_part_of_speech(PartOfSpeech::Noun).collect()), ["rosa", "manus", "cornū"]);
+    }
+}
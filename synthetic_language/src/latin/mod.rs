@@ -1,2 +1,6 @@
 pub mod declension;
 pub mod conjugation;
+pub mod dictionary;
+pub mod form_index;
+pub mod lexicon;
+pub mod verb;
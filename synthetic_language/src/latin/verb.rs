@@ -0,0 +1,259 @@
+use crate::latin::conjugation::*;
+use crate::*;
+
+/// The ending of the first person singular perfect, with and without its macron.
+const PERFECT_ENDINGS: [&str; 2] = ["ī", "i"];
+
+/// The ending of the supine, or of the perfect passive participle, which some dictionaries give as
+/// the fourth principal part instead.
+const SUPINE_ENDINGS: [&str; 2] = ["um", "us"];
+
+/// The stem on which a verb form is built.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stem {
+    /// The present stem, from the first principal part and the infinitive, e.g. "am-".
+    Present,
+    /// The perfect stem, from the third principal part, e.g. "amāv-".
+    Perfect,
+    /// The supine stem, from the fourth principal part, e.g. "amāt-".
+    Supine,
+}
+
+impl Stem {
+    /// The stem from which the form for `categories` is built: the perfect active system on the
+    /// perfect stem, the perfect passive system and the perfect passive and future active
+    /// participles on the supine stem, and everything else on the present stem.
+    pub fn for_categories(categories: VerbCategories) -> Self {
+        let VerbCategories(voice, mood, aspect, tense, _, _) = categories;
+
+        match (voice, mood, aspect) {
+            (Voice::Active, Mood::Participle, Aspect::Imperfective) if tense == Tense::Future => Stem::Supine,
+            (Voice::Active, Mood::Participle, _) => Stem::Present,
+            (Voice::Active, _, Aspect::Perfective) => Stem::Perfect,
+            (Voice::Passive, _, Aspect::Perfective) => Stem::Supine,
+            _ => Stem::Present,
+        }
+    }
+}
+
+/// A verb given by its four principal parts, e.g. "amō, amāre, amāvī, amātum". Each stem is kept
+/// as a `Word` of its own, so that the present system follows the verb's conjugation while the
+/// perfect and supine systems, which are the same for every conjugation, follow `PERFECT_SYSTEM` and
+/// `SUPINE_SYSTEM`.
+pub struct Verb<'a> {
+    present: Word<'a, VerbConjugation<'a>>,
+    perfect: Option<Word<'a, VerbConjugation<'a>>>,
+    supine: Option<Word<'a, VerbConjugation<'a>>>,
+}
+
+/// The infinitive endings which identify each conjugation, with and without macrons. An infinitive
+/// in "-ere" is ambiguous: the first principal part tells the second ("moneō") from the third
+/// ("regō") and the third "-iō" ("capiō").
+fn conjugation_of<'a>(first: &str, infinitive: &'a str) -> Option<(VerbConjugation<'static>, &'a str)> {
+    let strip = |endings: &[&str]| endings.iter().find_map(|ending| infinitive.strip_suffix(ending));
+
+    if let Some(stem) = strip(&["āre", "are"]) {
+        Some((FIRST_CONJUGATION, stem))
+    } else if let Some(stem) = strip(&["īre", "ire"]) {
+        Some((FOURTH_CONJUGATION, stem))
+    } else if let Some(stem) = strip(&["ēre"]) {
+        Some((SECOND_CONJUGATION, stem))
+    } else if let Some(stem) = strip(&["ere"]) {
+        if first.ends_with("eō") || first.ends_with("eo") {
+            Some((SECOND_CONJUGATION, stem))
+        } else if first.ends_with("iō") || first.ends_with("io") {
+            Some((THIRD_IO_CONJUGATION, stem))
+        } else {
+            Some((THIRD_CONJUGATION, stem))
+        }
+    } else {
+        None
+    }
+}
+
+/// Whether a principal part is given as missing, as in "—" for verbs without a supine.
+fn is_missing(part: &str) -> bool {
+    part.chars().all(|c| c == '-' || c == '—' || c == '–')
+}
+
+impl<'a> Verb<'a> {
+    /// Parse the principal parts of a regular active verb, separated by commas. The third and fourth
+    /// parts may be given as "—" if the verb has no perfect or supine; the fourth may also be given
+    /// as the perfect passive participle, e.g. "amātus".
+    pub fn from_principal_parts(principal_parts: &'a str) -> Result<Self, PrincipalPartsError> {
+        let parts = principal_parts.split(',').map(str::trim).collect::<Vec<_>>();
+        let [first, infinitive, perfect, supine] = parts[..] else {
+            return Err(PrincipalPartsError::Malformed { principal_parts: principal_parts.to_string() });
+        };
+        if first.is_empty() || infinitive.is_empty() {
+            return Err(PrincipalPartsError::Malformed { principal_parts: principal_parts.to_string() });
+        }
+
+        let (conjugation, present_stem) = conjugation_of(first, infinitive)
+            .filter(|(_, stem)| !stem.is_empty())
+            .ok_or_else(|| PrincipalPartsError::UnrecognizedInfinitive { infinitive: infinitive.to_string() })?;
+
+        let perfect = if is_missing(perfect) {
+            None
+        } else {
+            let stem = PERFECT_ENDINGS
+                .iter()
+                .find_map(|ending| perfect.strip_suffix(ending))
+                .filter(|stem| !stem.is_empty())
+                .ok_or_else(|| PrincipalPartsError::UnrecognizedPerfect { perfect: perfect.to_string() })?;
+            Some(Word::new(PERFECT_SYSTEM, perfect, stem))
+        };
+
+        let supine = if is_missing(supine) {
+            None
+        } else {
+            let stem = SUPINE_ENDINGS
+                .iter()
+                .find_map(|ending| supine.strip_suffix(ending))
+                .filter(|stem| !stem.is_empty())
+                .ok_or_else(|| PrincipalPartsError::UnrecognizedSupine { supine: supine.to_string() })?;
+            Some(Word::new(SUPINE_SYSTEM, supine, stem))
+        };
+
+        Ok(Verb {
+            present: Word::new(conjugation, first, present_stem),
+            perfect,
+            supine,
+        })
+    }
+
+    pub fn lemma(&self) -> &str {
+        self.present.lemma()
+    }
+
+    /// The conjugation of the present system.
+    pub fn conjugation(&self) -> &VerbConjugation<'a> {
+        self.present.inflection()
+    }
+
+    /// The preferred form for `categories`.
+    pub fn inflect(&self, categories: VerbCategories) -> Result<String, InflectionError> {
+        let word = match Stem::for_categories(categories) {
+            Stem::Present => Some(&self.present),
+            Stem::Perfect => self.perfect.as_ref(),
+            Stem::Supine => self.supine.as_ref(),
+        };
+
+        word.ok_or_else(|| InflectionError::Defective { lemma: self.lemma().to_string() })?
+            .inflect(categories)
+    }
+
+    /// Every accepted form for `categories`, preferred first.
+    pub fn inflect_all(&self, categories: VerbCategories) -> Vec<String> {
+        let word = match Stem::for_categories(categories) {
+            Stem::Present => Some(&self.present),
+            Stem::Perfect => self.perfect.as_ref(),
+            Stem::Supine => self.supine.as_ref(),
+        };
+
+        word.map(|word| word.inflect_all(categories)).unwrap_or_default()
+    }
+}
+
+/// The reasons a verb's principal parts may fail to parse.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PrincipalPartsError {
+    /// The principal parts are not four comma separated forms.
+    Malformed { principal_parts: String },
+    UnrecognizedInfinitive { infinitive: String },
+    UnrecognizedPerfect { perfect: String },
+    UnrecognizedSupine { supine: String },
+}
+
+impl std::fmt::Display for PrincipalPartsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let endings = |endings: &[&str]| endings.iter().map(|ending| format!("\"-{}\"", ending)).collect::<Vec<_>>().join(" or ");
+
+        match self {
+            PrincipalPartsError::Malformed { principal_parts } => {
+                write!(f, "\"{}\" is not four comma separated principal parts", principal_parts)
+            }
+            PrincipalPartsError::UnrecognizedInfinitive { infinitive } => {
+                write!(f, "\"{}\" is not the infinitive of any conjugation", infinitive)
+            }
+            PrincipalPartsError::UnrecognizedPerfect { perfect } => {
+                write!(f, "\"{}\" is not a perfect in {}", perfect, endings(&PERFECT_ENDINGS))
+            }
+            PrincipalPartsError::UnrecognizedSupine { supine } => {
+                write!(f, "\"{}\" is not a supine in {}", supine, endings(&SUPINE_ENDINGS))
+            }
+        }
+    }
+}
+
+impl std::error::Error for PrincipalPartsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_principal_parts() {
+        let amo = Verb::from_principal_parts("amō, amāre, amāvī, amātum").unwrap();
+        let rego = Verb::from_principal_parts("regō, regere, rēxī, rēctum").unwrap();
+        let capio = Verb::from_principal_parts("capiō, capere, cēpī, captum").unwrap();
+
+        assert_eq!(amo.lemma(), "amō");
+        assert_eq!(amo.inflect(VerbCategories(Voice::Active, Mood::Indicative, Aspect::Imperfective, Tense::Present, Number::Singular, Person::Third)).unwrap(), "amat");
+        assert_eq!(amo.inflect(VerbCategories(Voice::Active, Mood::Indicative, Aspect::Perfective, Tense::Present, Number::Plural, Person::Third)).unwrap(), "amāvērunt");
+        assert_eq!(
+            amo.inflect_all(VerbCategories(Voice::Active, Mood::Indicative, Aspect::Perfective, Tense::Present, Number::Plural, Person::Third)),
+            vec!["amāvērunt", "amāvēre"]
+        );
+        assert_eq!(amo.inflect(VerbCategories(Voice::Active, Mood::Subjunctive, Aspect::Perfective, Tense::Past, Number::Singular, Person::First)).unwrap(), "amāvissem");
+        assert_eq!(amo.inflect(VerbCategories(Voice::Passive, Mood::Indicative, Aspect::Perfective, Tense::Present, Number::Singular, Person::Third)).unwrap(), "amātus est");
+        assert_eq!(amo.inflect(VerbCategories(Voice::Active, Mood::Participle, Aspect::Imperfective, Tense::Future, Number::Singular, Person::Third)).unwrap(), "amātūrus");
+        assert_eq!(amo.inflect(VerbCategories(Voice::Active, Mood::Participle, Aspect::Imperfective, Tense::Present, Number::Singular, Person::Third)).unwrap(), "amāns");
+
+        assert_eq!(rego.inflect(VerbCategories(Voice::Active, Mood::Indicative, Aspect::Perfective, Tense::Future, Number::Singular, Person::First)).unwrap(), "rēxerō");
+        assert_eq!(rego.inflect(VerbCategories(Voice::Passive, Mood::Indicative, Aspect::Perfective, Tense::Past, Number::Plural, Person::Third)).unwrap(), "rēctī erant");
+        assert_eq!(capio.inflect(VerbCategories(Voice::Active, Mood::Indicative, Aspect::Perfective, Tense::Present, Number::Singular, Person::Second)).unwrap(), "cēpistī");
+        assert_eq!(capio.inflect(VerbCategories(Voice::Passive, Mood::Participle, Aspect::Perfective, Tense::Present, Number::Singular, Person::Third)).unwrap(), "captus");
+    }
+
+    #[test]
+    fn test_missing_principal_parts() {
+        let timeo = Verb::from_principal_parts("timeō, timēre, timuī, —").unwrap();
+
+        assert_eq!(timeo.inflect(VerbCategories(Voice::Active, Mood::Indicative, Aspect::Perfective, Tense::Present, Number::Singular, Person::First)).unwrap(), "timuī");
+        assert_eq!(timeo.inflect(VerbCategories(Voice::Passive, Mood::Indicative, Aspect::Perfective, Tense::Present, Number::Singular, Person::First)), Err(InflectionError::Defective { lemma: "timeō".to_string() }));
+        assert_eq!(
+            Verb::from_principal_parts("amō, amāre").err(),
+            Some(PrincipalPartsError::Malformed { principal_parts: "amō, amāre".to_string() })
+        );
+        assert_eq!(
+            Verb::from_principal_parts("amō, amāvī, amāre, amātum").err(),
+            Some(PrincipalPartsError::UnrecognizedInfinitive { infinitive: "amāvī".to_string() })
+        );
+
+        let perfect = Verb::from_principal_parts("amō, amāre, amāvit, amātum").err().unwrap();
+        assert_eq!(perfect.to_string(), "\"amāvit\" is not a perfect in \"-ī\" or \"-i\"");
+        let supine = Verb::from_principal_parts("amō, amāre, amāvī, amāta").err().unwrap();
+        assert_eq!(supine.to_string(), "\"amāta\" is not a supine in \"-um\" or \"-us\"");
+    }
+
+    #[test]
+    fn test_stems_do_not_overlap() {
+        let conjugations = [FIRST_CONJUGATION, SECOND_CONJUGATION, THIRD_CONJUGATION, THIRD_IO_CONJUGATION, FOURTH_CONJUGATION];
+
+        for categories in VerbCategories::iter_through_variants() {
+            let stem = Stem::for_categories(categories);
+            for conjugation in conjugations {
+                if stem != Stem::Present {
+                    assert_eq!(conjugation.suffix(categories), Err(InflectionError::NotInInflection), "{:?}", categories);
+                }
+            }
+            if stem != Stem::Perfect {
+                assert_eq!(PERFECT_SYSTEM.suffix(categories), Err(InflectionError::NotInInflection), "{:?}", categories);
+            }
+            if stem != Stem::Supine {
+                assert_eq!(SUPINE_SYSTEM.suffix(categories), Err(InflectionError::NotInInflection), "{:?}", categories);
+            }
+        }
+    }
+}
@@ -1,12 +1,30 @@
+pub mod guess;
 pub mod latin;
+pub mod normalize;
+pub mod table;
+#[cfg(feature = "serde")]
+mod serde_support;
+
+use std::borrow::Cow;
 
 use category_derive::*;
 
 /// An `InflectionalCategory` is a salient category used when inflecting a word, such as
 /// gender, number, case, tense, aspect, mood, etc.
 pub trait InflectionalCategory: PartialEq {
+    /// The name of the category, such as "Case".
+    const NAME: &'static str;
+    /// The number of variants.
+    const COUNT: usize;
+
     fn index(&self) -> usize;
 
+    /// The name of the variant, such as "Genitive".
+    fn name(&self) -> &'static str;
+
+    /// The variant whose `index` is `index`, if there is one.
+    fn from_index(index: usize) -> Option<Self> where Self: Sized;
+
     fn iter_through_variants() -> impl Iterator<Item = Self>;
 }
 
@@ -14,63 +32,357 @@ pub trait InflectionalCategory: PartialEq {
 /// inflection of a word, such as gender, number, and case for a Latin adjective.
 pub trait InflectionalCategorySet: PartialEq {
     type IndexType;
+    /// The name of the category set, such as "NominalCategories".
+    const NAME: &'static str;
+    /// The names of the categories in the set, in order, such as `["Gender", "Number", "Case"]`.
+    const CATEGORY_NAMES: &'static [&'static str];
+    /// The number of distinct sets of categories.
+    const COUNT: usize;
+
     fn index(&self) -> Self::IndexType;
 
+    /// The names of the variants of each category, in order, such as
+    /// `["Feminine", "Plural", "Genitive"]`.
+    fn names(&self) -> Vec<&'static str>;
+
+    /// The set of categories whose `index` is `index`, if there is one. This lets compact indices,
+    /// e.g. in saved progress or lookup tables, be turned back into typed categories.
+    fn from_index(index: Self::IndexType) -> Option<Self> where Self: Sized;
+
     fn iter_through_variants() -> impl Iterator<Item = Self>;
 }
 
 /// An `Inflection` is a set of transformations on a root which, when given the relevant
-/// `InflectionalCategorySet` will give a fully inflected word (if it exists).
+/// `InflectionalCategorySet` will give a fully inflected word (if it exists). Some forms are not
+/// built on the root at all but are the lemma itself, so the lemma is given as well.
 pub trait Inflection<'a> {
     type CategorySet: InflectionalCategorySet;
 
-    fn inflect(&self, root: &'a str, categories: Self::CategorySet) -> Option<String>;
+    /// Every accepted form for `categories`, preferred first. Doublets such as the perfect
+    /// "amāvērunt"/"amāvēre" give more than one; a form which does not exist gives none.
+    fn inflect_all(&self, lemma: &str, root: &str, categories: Self::CategorySet) -> Vec<String>;
+
+    /// The preferred form for `categories`.
+    fn inflect(&self, lemma: &str, root: &str, categories: Self::CategorySet) -> Result<String, InflectionError> {
+        self.inflect_all(lemma, root, categories)
+            .into_iter()
+            .next()
+            .ok_or(InflectionError::NotInInflection)
+    }
+}
+
+/// A single cell of a `SuffixInflection`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Suffix<'a> {
+    /// The form is the root followed by this ending.
+    Ending(&'a str),
+    /// The form is the lemma as-is, as in the nominative singular of third declension nouns like
+    /// "corpus" and "rēx", whose root ("corpor-", "rēg-") does not appear in it.
+    Lemma,
+}
+
+impl<'a> std::fmt::Display for Suffix<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Suffix::Ending(ending) => write!(f, "{}", ending),
+            Suffix::Lemma => write!(f, "(lemma)"),
+        }
+    }
 }
 
 /// A `SuffixInflection` is a special case of an `Inflection` in which roots are merely given
 /// suffixes in the happy path. There are cases, such as the Latin third declension neuter, in
-/// which the lemma form and not the root is used. We can deal with this by using the `Option<>`
-/// variant `None` in those cases and adding cases to deal with that.
+/// which the lemma form and not the root is used; these are given by `Suffix::Lemma`. A
+/// form with no suffixes does not exist. Where more than one form is accepted, the suffixes are
+/// given in order of preference.
 pub trait SuffixInflection<'a> {
     type CategorySet: InflectionalCategorySet;
 
-    fn suffix (&self, categories: Self::CategorySet) -> Option<&'a str>;
+    fn suffixes(&self, categories: Self::CategorySet) -> impl Iterator<Item = Suffix<'a>>;
+
+    /// The preferred suffix for `categories`.
+    fn suffix(&self, categories: Self::CategorySet) -> Result<Suffix<'a>, InflectionError> {
+        self.suffixes(categories).next().ok_or(InflectionError::NotInInflection)
+    }
 }
 
 impl<'a, T> Inflection<'a> for T where T: SuffixInflection<'a> {
     type CategorySet = T::CategorySet;
 
-    fn inflect(&self, root: &'a str, categories: T::CategorySet) -> Option<String> {
-        let suffix = self.suffix(categories)?;
-        let mut result = String::with_capacity(root.len() + suffix.len());
+    fn inflect_all(&self, lemma: &str, root: &str, categories: T::CategorySet) -> Vec<String> {
+        self.suffixes(categories)
+            .map(|suffix| match suffix {
+                Suffix::Ending(suffix) => {
+                    let mut result = String::with_capacity(root.len() + suffix.len());
 
-        result.push_str(root);
-        result.push_str(suffix);
-        Some(result)
+                    result.push_str(root);
+                    result.push_str(suffix);
+                    result
+                }
+                Suffix::Lemma => lemma.to_string(),
+            })
+            .collect()
     }
 }
 
-pub struct IrregularForm<'a, InflCatSet: InflectionalCategorySet>(InflCatSet, Option<&'a str>,);
+/// A form of a word which does not follow its inflection. A form of `None` means the word has no
+/// form for those categories at all. A word may have several irregular forms for the same
+/// categories, in which case they are all accepted, the first being preferred.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IrregularForm<'a, InflCatSet: InflectionalCategorySet>(pub InflCatSet, pub Option<Cow<'a, str>>);
 
+/// A word: its lemma and root and the inflection which gives its forms. The lemma, root and
+/// irregular forms are either borrowed, as from a string literal or a line of a dictionary which
+/// outlives the word, or owned, as for an `OwnedWord` read from a file at runtime.
+#[derive(Clone)]
 pub struct Word<'a, Infl: Inflection<'a>> {
     inflection: Infl,
-    lemma: &'a str,
-    root: &'a str,
+    lemma: Cow<'a, str>,
+    root: Cow<'a, str>,
     regular: bool,
     irregular_forms: Vec<IrregularForm<'a, Infl::CategorySet>>,
+    /// Every set of categories for which the word has no form at all.
+    defective: Vec<Infl::CategorySet>,
 }
 
 impl<'a, Infl: Inflection<'a>> Word<'a, Infl> {
-    pub fn inflect(&self, categories: Infl::CategorySet) -> Option<String> {
-        if self.regular {
-            self.inflection.inflect(self.root, categories)
-        } else {
-            for irregular_form in &self.irregular_forms {
-                if irregular_form.0 == categories {
-                    return Some(irregular_form.1?.to_string())
-                }
+    /// Create a regular word, i.e. one whose every form is given by its `inflection` applied to
+    /// its `root`.
+    pub fn new(inflection: Infl, lemma: impl Into<Cow<'a, str>>, root: impl Into<Cow<'a, str>>) -> Self {
+        Word {
+            inflection,
+            lemma: lemma.into(),
+            root: root.into(),
+            regular: true,
+            irregular_forms: Vec::new(),
+            defective: Vec::new(),
+        }
+    }
+
+    /// Start building a word whose forms are given by `inflection` applied to `root`, with any
+    /// irregular forms added to the builder.
+    pub fn builder(
+        inflection: Infl,
+        lemma: impl Into<Cow<'a, str>>,
+        root: impl Into<Cow<'a, str>>,
+    ) -> WordBuilder<'a, Infl> {
+        WordBuilder {
+            inflection,
+            lemma: lemma.into(),
+            root: root.into(),
+            stem_change: false,
+            irregular_forms: Vec::new(),
+            defective: Vec::new(),
+        }
+    }
+
+    pub fn lemma(&self) -> &str {
+        &self.lemma
+    }
+
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    pub fn inflection(&self) -> &Infl {
+        &self.inflection
+    }
+
+    /// The preferred form for `categories`, or why there is none.
+    pub fn inflect(&self, categories: Infl::CategorySet) -> Result<String, InflectionError> {
+        if self.defective.contains(&categories) {
+            return Err(InflectionError::Defective { lemma: self.lemma.to_string() });
+        }
+        if self.irregular_forms.iter().any(|irregular_form| irregular_form.0 == categories) {
+            return self
+                .inflect_all(categories)
+                .into_iter()
+                .next()
+                .ok_or_else(|| InflectionError::IrregularlyMissing { lemma: self.lemma.to_string() });
+        }
+
+        self.inflection.inflect(&self.lemma, &self.root, categories)
+    }
+
+    /// Every accepted form for `categories`, preferred first. Irregular forms replace all of the
+    /// forms given by the inflection, and a word which is defective in `categories` has none.
+    pub fn inflect_all(&self, categories: Infl::CategorySet) -> Vec<String> {
+        if self.defective.contains(&categories) {
+            return Vec::new();
+        }
+        if !self.regular {
+            let irregular_forms = self
+                .irregular_forms
+                .iter()
+                .filter(|irregular_form| irregular_form.0 == categories)
+                .collect::<Vec<_>>();
+            if !irregular_forms.is_empty() {
+                return irregular_forms
+                    .iter()
+                    .filter_map(|irregular_form| irregular_form.1.as_deref())
+                    .map(str::to_string)
+                    .collect();
+            }
+        }
+        self.inflection.inflect_all(&self.lemma, &self.root, categories)
+    }
+
+    /// Every set of categories whose form of this word is `form`. Syncretic forms, such as the
+    /// Latin dative and ablative plural, give more than one.
+    pub fn analyze(&self, form: &str) -> Vec<Infl::CategorySet>
+    where
+        Infl::CategorySet: Clone,
+    {
+        Infl::CategorySet::iter_through_variants()
+            .filter(|categories| self.inflect_all(categories.clone()).iter().any(|f| f == form))
+            .collect()
+    }
+}
+
+/// A `Word` which owns its lemma, root and irregular forms, so that it may be kept for the life of
+/// the program however it was read.
+pub type OwnedWord<Infl> = Word<'static, Infl>;
+
+pub struct WordBuilder<'a, Infl: Inflection<'a>> {
+    inflection: Infl,
+    lemma: Cow<'a, str>,
+    root: Cow<'a, str>,
+    stem_change: bool,
+    irregular_forms: Vec<IrregularForm<'a, Infl::CategorySet>>,
+    defective: Vec<Infl::CategorySet>,
+}
+
+impl<'a, Infl: Inflection<'a>> WordBuilder<'a, Infl> {
+    /// Allow a root which is not a prefix of the lemma, as in "rēx, rēgis" or "ager, agrī".
+    pub fn stem_change(mut self) -> Self {
+        self.stem_change = true;
+        self
+    }
+
+    /// Override the form for `categories`. A form of `None` means the word has no such form. Calling
+    /// this again for the same categories adds another accepted form after the first. A form may be
+    /// borrowed, as `Some("rēx".into())`, or owned, as `Some(form.into())` for a `String`.
+    pub fn irregular(mut self, categories: Infl::CategorySet, form: Option<Cow<'a, str>>) -> Self {
+        self.irregular_forms.push(IrregularForm(categories, form));
+        self
+    }
+
+    /// Mark every set of categories for which `missing` holds as having no form, whatever the
+    /// inflection and any irregular forms give, as for a noun with no singular or no locative.
+    /// Calling this again marks more categories.
+    pub fn defective(mut self, missing: impl Fn(&Infl::CategorySet) -> bool) -> Self {
+        let defective = Infl::CategorySet::iter_through_variants()
+            .filter(|categories| missing(categories) && !self.defective.contains(categories))
+            .collect::<Vec<_>>();
+        self.defective.extend(defective);
+        self
+    }
+
+    pub fn build(self) -> Result<Word<'a, Infl>, WordError> {
+        if self.lemma.is_empty() {
+            return Err(WordError::EmptyLemma);
+        }
+        if self.root.is_empty() {
+            return Err(WordError::EmptyRoot { lemma: self.lemma.to_string() });
+        }
+        if !self.stem_change && !self.lemma.starts_with(&*self.root) {
+            return Err(WordError::RootNotPrefixOfLemma {
+                lemma: self.lemma.to_string(),
+                root: self.root.to_string(),
+            });
+        }
+        for (i, irregular_form) in self.irregular_forms.iter().enumerate() {
+            let conflicts = |previous: &IrregularForm<'a, Infl::CategorySet>| {
+                previous.0 == irregular_form.0
+                    && (previous.1 == irregular_form.1 || previous.1.is_none() || irregular_form.1.is_none())
+            };
+            if self.irregular_forms[..i].iter().any(conflicts) {
+                return Err(WordError::DuplicateIrregularForm { lemma: self.lemma.to_string() });
             }
-            self.inflection.inflect(self.root, categories)
         }
+
+        Ok(Word {
+            inflection: self.inflection,
+            lemma: self.lemma,
+            root: self.root,
+            regular: self.irregular_forms.is_empty(),
+            irregular_forms: self.irregular_forms,
+            defective: self.defective,
+        })
     }
-}
\ No newline at end of file
+}
+
+/// The reasons a word may have no form for a set of categories.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InflectionError {
+    /// The inflection has no such form for any word, as the fifth declension has no neuter.
+    NotInInflection,
+    /// The word is defective in these categories, as "castra" has no singular.
+    Defective { lemma: String },
+    /// An irregular form of the word says it has no such form.
+    IrregularlyMissing { lemma: String },
+}
+
+impl std::fmt::Display for InflectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            InflectionError::NotInInflection => write!(f, "the inflection has no such form"),
+            InflectionError::Defective { lemma } => write!(f, "\"{}\" is defective and has no such form", lemma),
+            InflectionError::IrregularlyMissing { lemma } => {
+                write!(f, "\"{}\" is irregular and has no such form", lemma)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InflectionError {}
+
+/// The reasons a `WordBuilder` may refuse to build a word.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WordError {
+    EmptyLemma,
+    EmptyRoot { lemma: String },
+    RootNotPrefixOfLemma { lemma: String, root: String },
+    /// The same irregular form was given twice, or a form was given as both missing and present.
+    DuplicateIrregularForm { lemma: String },
+}
+
+impl std::fmt::Display for WordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            WordError::EmptyLemma => write!(f, "the lemma is empty"),
+            WordError::EmptyRoot { lemma } => write!(f, "the root of \"{}\" is empty", lemma),
+            WordError::RootNotPrefixOfLemma { lemma, root } => write!(
+                f,
+                "the root \"{}\" is not a prefix of the lemma \"{}\"; mark the word as having a stem change",
+                root, lemma
+            ),
+            WordError::DuplicateIrregularForm { lemma } => {
+                write!(f, "\"{}\" has conflicting irregular forms for the same categories", lemma)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WordError {}
+
+/// Every `(lemma, categories)` among `words` which produces `form`.
+pub fn analyze<'w, 'a: 'w, Infl>(
+    words: impl IntoIterator<Item = &'w Word<'a, Infl>>,
+    form: &str,
+) -> Vec<(&'w str, Infl::CategorySet)>
+where
+    Infl: Inflection<'a> + 'w,
+    Infl::CategorySet: Clone,
+{
+    words
+        .into_iter()
+        .flat_map(|word| {
+            word.analyze(form)
+                .into_iter()
+                .map(move |categories| (word.lemma(), categories))
+        })
+        .collect()
+}
@@ -0,0 +1,114 @@
+//! Unicode normalization of forms, and comparison of forms which differ only in vowel length.
+//!
+//! The suffix tables write long vowels with precomposed macrons ("ā", "ōrum"), but the same form
+//! may also arrive decomposed, with a combining macron after the vowel, or with no macrons at all.
+
+use unicode_normalization::UnicodeNormalization;
+
+pub const COMBINING_MACRON: char = '\u{304}';
+pub const COMBINING_BREVE: char = '\u{306}';
+
+/// The composed (NFC) form of `s`, in which long vowels are single characters such as "ā".
+pub fn nfc(s: &str) -> String {
+    s.nfc().collect()
+}
+
+/// The decomposed (NFD) form of `s`, in which long vowels are a vowel followed by
+/// `COMBINING_MACRON`.
+pub fn nfd(s: &str) -> String {
+    s.nfd().collect()
+}
+
+/// `s` in NFC with every macron and breve removed, so that "rosārum" and "rosarum" are the same.
+pub fn strip_macrons(s: &str) -> String {
+    s.nfd()
+        .filter(|&c| c != COMBINING_MACRON && c != COMBINING_BREVE)
+        .nfc()
+        .collect()
+}
+
+/// A letter of which one form has a macron and the other does not.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VowelLength {
+    /// The index of the letter, ignoring macrons and breves.
+    pub index: usize,
+    /// The letter without its macron.
+    pub vowel: char,
+    /// Whether the letter is long in the expected form.
+    pub long: bool,
+}
+
+/// Split `s` into its letters without their macrons and breves, noting which vowels carried a
+/// macron. A macron on anything but a vowel is not vowel length, so it is kept as a letter of its
+/// own.
+fn letters(s: &str) -> Vec<(char, bool)> {
+    let is_vowel = |c: char| "aeiouyAEIOUY".contains(c);
+    let mut letters: Vec<(char, bool)> = Vec::new();
+
+    for c in s.nfd() {
+        match (c, letters.last_mut()) {
+            (COMBINING_MACRON, Some((letter, long))) if is_vowel(*letter) => *long = true,
+            (COMBINING_BREVE, Some(_)) => {}
+            _ => letters.push((c, false)),
+        }
+    }
+
+    letters
+}
+
+/// Compare `form` with `expected`, ignoring normalization. Returns `None` if they differ in more
+/// than vowel length, and otherwise every letter whose length differs (none if they are the same).
+pub fn vowel_length_differences(form: &str, expected: &str) -> Option<Vec<VowelLength>> {
+    let form = letters(form);
+    let expected = letters(expected);
+
+    if form.len() != expected.len() {
+        return None;
+    }
+
+    let mut differences = Vec::new();
+    for (index, (&(c, long), &(expected_c, expected_long))) in form.iter().zip(&expected).enumerate() {
+        if c != expected_c {
+            return None;
+        }
+        if long != expected_long {
+            differences.push(VowelLength { index, vowel: expected_c, long: expected_long });
+        }
+    }
+
+    Some(differences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization() {
+        let decomposed = "rosa\u{304}rum";
+
+        assert_eq!(nfc(decomposed), "rosārum");
+        assert_eq!(nfd("rosārum"), decomposed);
+        assert_eq!(strip_macrons("rosārum"), "rosarum");
+        assert_eq!(strip_macrons(decomposed), "rosarum");
+        assert_eq!(strip_macrons("Rōma"), "Roma");
+    }
+
+    #[test]
+    fn test_vowel_length_differences() {
+        assert_eq!(vowel_length_differences("rosa\u{304}rum", "rosārum"), Some(vec![]));
+        assert_eq!(
+            vowel_length_differences("rosarum", "rosārum"),
+            Some(vec![VowelLength { index: 3, vowel: 'a', long: true }])
+        );
+        assert_eq!(
+            vowel_length_differences("dominō", "dominī"),
+            None
+        );
+        assert_eq!(
+            vowel_length_differences("rosā", "rosa"),
+            Some(vec![VowelLength { index: 3, vowel: 'a', long: false }])
+        );
+        assert_eq!(vowel_length_differences("rosam\u{304}", "rosam"), None);
+    }
+}
@@ -0,0 +1,152 @@
+//! Serialization of words, behind the `serde` feature. The categories, category sets, tables,
+//! suffixes and irregular forms derive or generate their own implementations. A compiled table is
+//! only serialized; it is read back as an owned `table::Table`.
+//!
+//! A word is serialized as its inflection, lemma, root, whether its root is changed from the lemma,
+//! irregular forms and defective categories, and is deserialized through `WordBuilder`, so that a
+//! word read from a file is checked just like one built in code: a root which is not a prefix of
+//! the lemma is rejected unless `stem_change` is set. A deserialized word owns its strings, so that it may outlive its source as
+//! an `OwnedWord`.
+
+use std::borrow::Cow;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::*;
+
+#[derive(Serialize)]
+#[serde(rename = "Word", bound = "Infl: Serialize, Infl::CategorySet: Serialize")]
+struct WordRef<'w, 'a, Infl: Inflection<'a>> {
+    inflection: &'w Infl,
+    lemma: &'w str,
+    root: &'w str,
+    stem_change: bool,
+    irregular_forms: &'w [IrregularForm<'a, Infl::CategorySet>],
+    defective: &'w [Infl::CategorySet],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Word", bound = "Infl: Deserialize<'de>, Infl::CategorySet: Deserialize<'de>")]
+struct WordData<'a, Infl: Inflection<'a>> {
+    inflection: Infl,
+    lemma: Cow<'a, str>,
+    root: Cow<'a, str>,
+    #[serde(default)]
+    stem_change: bool,
+    #[serde(default)]
+    irregular_forms: Vec<IrregularForm<'a, Infl::CategorySet>>,
+    #[serde(default)]
+    defective: Vec<Infl::CategorySet>,
+}
+
+impl<'a, Infl> Serialize for Word<'a, Infl>
+where
+    Infl: Inflection<'a> + Serialize,
+    Infl::CategorySet: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        WordRef {
+            inflection: &self.inflection,
+            lemma: &self.lemma,
+            root: &self.root,
+            // A built word whose lemma does not start with its root must have been allowed to.
+            stem_change: !self.lemma.starts_with(&*self.root),
+            irregular_forms: &self.irregular_forms,
+            defective: &self.defective,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, 'a, Infl> Deserialize<'de> for Word<'a, Infl>
+where
+    Infl: Inflection<'a> + Deserialize<'de>,
+    Infl::CategorySet: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = WordData::<'a, Infl>::deserialize(deserializer)?;
+
+        let mut builder = Word::builder(data.inflection, data.lemma, data.root);
+        if data.stem_change {
+            builder = builder.stem_change();
+        }
+        for IrregularForm(categories, form) in data.irregular_forms {
+            builder = builder.irregular(categories, form);
+        }
+        let defective = data.defective;
+        builder = builder.defective(|categories| defective.contains(categories));
+
+        builder.build().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::latin::declension::*;
+    use crate::table::Table;
+    use crate::*;
+
+    #[test]
+    fn test_categories() {
+        let categories = NominalCategories(Gender::Feminine, Number::Plural, Case::Genitive);
+        let json = serde_json::to_string(&categories).unwrap();
+
+        assert_eq!(json, r#"["Feminine","Plural","Genitive"]"#);
+        assert_eq!(serde_json::from_str::<NominalCategories>(&json).unwrap(), categories);
+        assert!(serde_json::from_str::<Case>(r#""Instrumental""#).is_err());
+    }
+
+    #[test]
+    fn test_tables() {
+        let json = serde_json::to_string(&FIRST_DECLENSION).unwrap();
+        let first: Table<NominalCategories> = serde_json::from_str(&json).unwrap();
+
+        assert!(json.starts_with(r#"{"name":"First Declension","suffixes":[[["Feminine","Singular","Nominative"],[{"Ending":"a"}]]"#));
+        assert_eq!(first.name(), "First Declension");
+        for categories in NominalCategories::ALL {
+            assert!((&first).suffixes(categories).eq(FIRST_DECLENSION.suffixes(categories)));
+        }
+        assert_eq!(serde_json::to_string(&first).unwrap(), json);
+
+        let tiny = r#"{"name": "Tiny", "suffixes": [[["Neuter", "Plural", "Nominative"], ["Lemma", {"Ending": "a"}]]]}"#;
+        let error = serde_json::from_str::<Table<NominalCategories>>(tiny).unwrap_err();
+        assert!(error.to_string().starts_with("\"Feminine Singular Nominative\" is missing"), "{}", error);
+
+        let mut holes = serde_json::from_str::<serde_json::Value>(&serde_json::to_string(&THIRD_DECLENSION).unwrap()).unwrap();
+        for cell in holes["suffixes"].as_array_mut().unwrap() {
+            cell[1] = serde_json::json!([]);
+        }
+        let empty: Table<NominalCategories> = serde_json::from_value(holes).unwrap();
+        let categories = NominalCategories(Gender::Neuter, Number::Plural, Case::Genitive);
+        assert_eq!((&empty).suffix(categories), Err(InflectionError::NotInInflection));
+
+        let repeated = r#"{"name": "Tiny", "suffixes": [[["Neuter", "Plural", "Nominative"], []], [["Neuter", "Plural", "Nominative"], []]]}"#;
+        let error = serde_json::from_str::<Table<NominalCategories>>(repeated).unwrap_err();
+        assert!(error.to_string().starts_with("\"Neuter Plural Nominative\" is given more than once"), "{}", error);
+    }
+
+    #[test]
+    fn test_words() {
+        let rex = Word::builder(THIRD_DECLENSION, "rēx", "rēg")
+            .stem_change()
+            .irregular(NominalCategories(Gender::Masculine, Number::Singular, Case::Vocative), Some("rēx".into()))
+            .defective(NominalCategories::is_locative)
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&rex).unwrap();
+        let loaded: OwnedWord<Arc<Table<NominalCategories>>> = serde_json::from_str(&json).unwrap();
+
+        for categories in NominalCategories::ALL {
+            assert_eq!(loaded.inflect(categories), rex.inflect(categories));
+        }
+
+        assert!(json.contains(r#""stem_change":true"#));
+        let unchecked = json.replace(r#""root":"rēg""#, r#""root":"""#);
+        assert!(serde_json::from_str::<OwnedWord<Arc<Table<NominalCategories>>>>(&unchecked).is_err());
+        let mistyped = json.replace(r#""stem_change":true,"#, "");
+        assert!(serde_json::from_str::<OwnedWord<Arc<Table<NominalCategories>>>>(&mistyped).is_err());
+    }
+}
@@ -0,0 +1,487 @@
+//! Suffix tables which own their endings, such as those read at runtime rather than compiled in
+//! with `suffixes!`.
+//!
+//! With the `loader` feature, a `Table` can be read from TOML or JSON. The file gives the table's
+//! name, the name of its category set, and its cells, nested by category and labeled with the
+//! variants' names. A key may name several categories at once, separated by spaces:
+//! ```toml
+//! name = "First Declension"
+//! categories = "NominalCategories"
+//!
+//! [cells.Feminine.Singular]
+//! Nominative = "a"
+//! Genitive = "ae"
+//! # ...
+//!
+//! [cells."Feminine Plural"]
+//! Nominative = "ae"
+//! Dative = ["īs", "ābus"]
+//! # ...
+//!
+//! [cells.Masculine]
+//! # ...
+//!
+//! [cells]
+//! Neuter = "None"
+//! ```
+//! A cell is a suffix, a list of accepted suffixes, preferred first, or `"(lemma)"` for the
+//! lemma itself. `"None"`, `[]` or, in JSON, `null` is a hole, and may stand for every cell below
+//! it. Every cell must be given exactly once, and every name must be a variant of the category it
+//! stands for.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::*;
+
+/// A single cell of a `Table`, owning its ending.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename = "Suffix"))]
+enum OwnedSuffix {
+    Ending(String),
+    Lemma,
+}
+
+impl OwnedSuffix {
+    fn as_suffix(&self) -> Suffix<'_> {
+        match self {
+            OwnedSuffix::Ending(ending) => Suffix::Ending(ending),
+            OwnedSuffix::Lemma => Suffix::Lemma,
+        }
+    }
+}
+
+/// A suffix table which owns its name and endings, such as one loaded from a file. It is used
+/// either by reference, as a `Word<'a, &'a Table<C>>`, or shared, as an `OwnedWord<Arc<Table<C>>>`
+/// which may be kept for the life of the program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table<C> {
+    name: String,
+    suffixes: Vec<Vec<OwnedSuffix>>,
+    categories: PhantomData<C>,
+}
+
+impl<C: InflectionalCategorySet<IndexType = usize>> Table<C> {
+    /// An owned copy of another table, such as one of the compiled Latin declensions.
+    pub fn from_suffix_inflection<'a>(name: impl Into<String>, inflection: &impl SuffixInflection<'a, CategorySet = C>) -> Self {
+        let suffixes = C::iter_through_variants()
+            .map(|categories| {
+                inflection
+                    .suffixes(categories)
+                    .map(|suffix| match suffix {
+                        Suffix::Ending(ending) => OwnedSuffix::Ending(ending.to_string()),
+                        Suffix::Lemma => OwnedSuffix::Lemma,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Table { name: name.into(), suffixes, categories: PhantomData }
+    }
+
+    /// A table from the cells read from a file, indexed by their categories, every one of which
+    /// must be given. An empty cell is a hole.
+    #[cfg(feature = "serde")]
+    fn from_cells(name: String, cells: Vec<Option<Vec<OwnedSuffix>>>) -> Result<Self, TableError> {
+        let mut suffixes = Vec::with_capacity(C::COUNT);
+        for (cell, categories) in cells.into_iter().zip(C::iter_through_variants()) {
+            suffixes.push(cell.ok_or_else(|| TableError::MissingCell { cell: categories.names().join(" ") })?);
+        }
+
+        Ok(Table { name, suffixes, categories: PhantomData })
+    }
+}
+
+impl<C> Table<C> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<'a, C> SuffixInflection<'a> for &'a Table<C>
+where
+    C: InflectionalCategorySet<IndexType = usize>,
+{
+    type CategorySet = C;
+
+    fn suffixes(&self, categories: C) -> impl Iterator<Item = Suffix<'a>> {
+        let table: &'a Table<C> = self;
+
+        table.suffixes[categories.index()].iter().map(OwnedSuffix::as_suffix)
+    }
+}
+
+impl<'a, C> Inflection<'a> for Arc<Table<C>>
+where
+    C: InflectionalCategorySet<IndexType = usize>,
+{
+    type CategorySet = C;
+
+    fn inflect_all(&self, lemma: &str, root: &str, categories: C) -> Vec<String> {
+        self.as_ref().inflect_all(lemma, root, categories)
+    }
+}
+
+/// With the `serde` feature, a table is written as a compiled table is, as its name and a list of
+/// every set of categories with its suffixes, so that a compiled table may be saved and read back
+/// as a `Table`. As when a table is loaded from TOML or JSON, every set of categories must be given
+/// exactly once, with no suffixes for a hole.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename = "Table", bound = "C: Serialize")]
+    struct TableRef<'t, C> {
+        name: &'t str,
+        suffixes: Vec<(C, &'t [OwnedSuffix])>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "Table", bound = "C: Deserialize<'de>")]
+    struct TableData<C> {
+        name: String,
+        suffixes: Vec<(C, Vec<OwnedSuffix>)>,
+    }
+
+    impl<C> Serialize for Table<C>
+    where
+        C: InflectionalCategorySet<IndexType = usize> + Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let suffixes = C::iter_through_variants()
+                .map(|categories| {
+                    let cell = self.suffixes[categories.index()].as_slice();
+                    (categories, cell)
+                })
+                .collect();
+            TableRef { name: &self.name, suffixes }.serialize(serializer)
+        }
+    }
+
+    impl<'de, C> Deserialize<'de> for Table<C>
+    where
+        C: InflectionalCategorySet<IndexType = usize> + Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = TableData::<C>::deserialize(deserializer)?;
+
+            let mut cells = vec![None; C::COUNT];
+            for (categories, cell) in data.suffixes {
+                if cells[categories.index()].replace(cell).is_some() {
+                    return Err(D::Error::custom(TableError::DuplicateCell { cell: categories.names().join(" ") }));
+                }
+            }
+
+            Table::from_cells(data.name, cells).map_err(D::Error::custom)
+        }
+    }
+}
+
+#[cfg(feature = "loader")]
+mod loader {
+    use std::collections::BTreeMap;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct TableFile {
+        name: String,
+        categories: String,
+        cells: BTreeMap<String, Node>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Node {
+        Hole,
+        Suffix(String),
+        Suffixes(Vec<String>),
+        Categories(BTreeMap<String, Node>),
+    }
+
+    /// The cells of a table as they are filled in from a file.
+    struct Cells<C> {
+        /// The names of each category's variants, in order.
+        variants: Vec<Vec<&'static str>>,
+        /// Every set of categories with its names.
+        all: Vec<(C, Vec<&'static str>)>,
+        suffixes: Vec<Option<Vec<OwnedSuffix>>>,
+    }
+
+    impl<C: InflectionalCategorySet<IndexType = usize>> Cells<C> {
+        fn new() -> Self {
+            let all = C::iter_through_variants()
+                .map(|categories| {
+                    let names = categories.names();
+                    (categories, names)
+                })
+                .collect::<Vec<_>>();
+            let variants = (0..C::CATEGORY_NAMES.len())
+                .map(|i| {
+                    let mut variants = Vec::new();
+                    for (_, names) in &all {
+                        if !variants.contains(&names[i]) {
+                            variants.push(names[i]);
+                        }
+                    }
+                    variants
+                })
+                .collect();
+
+            Cells { variants, all, suffixes: vec![None; C::COUNT] }
+        }
+
+        fn fill(&mut self, path: &mut Vec<&'static str>, key: &str, node: Node) -> Result<(), TableError> {
+            let depth = path.len();
+            for name in key.split_whitespace() {
+                let i = path.len();
+                let Some(variants) = self.variants.get(i) else {
+                    return Err(TableError::TooManyNames { cell: format!("{} {}", path.join(" "), name) });
+                };
+                let Some(&variant) = variants.iter().find(|&&variant| variant == name) else {
+                    return Err(TableError::UnknownVariant { category: C::CATEGORY_NAMES[i], name: name.to_string() });
+                };
+                path.push(variant);
+            }
+
+            let result = match node {
+                Node::Categories(children) => children
+                    .into_iter()
+                    .try_for_each(|(key, child)| self.fill(path, &key, child)),
+                Node::Hole => self.set(path, Vec::new()),
+                Node::Suffix(suffix) if suffix == "None" => self.set(path, Vec::new()),
+                Node::Suffix(suffix) => self.set_cell(path, vec![suffix]),
+                Node::Suffixes(suffixes) if suffixes.is_empty() => self.set(path, Vec::new()),
+                Node::Suffixes(suffixes) => self.set_cell(path, suffixes),
+            };
+            path.truncate(depth);
+            result
+        }
+
+        /// Fill the single cell named by `path`, which must name every category.
+        fn set_cell(&mut self, path: &[&'static str], suffixes: Vec<String>) -> Result<(), TableError> {
+            if path.len() < C::CATEGORY_NAMES.len() {
+                return Err(TableError::Incomplete {
+                    cell: path.join(" "),
+                    category: C::CATEGORY_NAMES[path.len()],
+                });
+            }
+
+            let suffixes = suffixes
+                .into_iter()
+                .map(|suffix| if suffix == "(lemma)" { OwnedSuffix::Lemma } else { OwnedSuffix::Ending(suffix) })
+                .collect();
+            self.set(path, suffixes)
+        }
+
+        /// Fill every cell whose names start with `path`.
+        fn set(&mut self, path: &[&'static str], suffixes: Vec<OwnedSuffix>) -> Result<(), TableError> {
+            for (categories, names) in &self.all {
+                if names.starts_with(path) {
+                    let cell = &mut self.suffixes[categories.index()];
+                    if cell.is_some() {
+                        return Err(TableError::DuplicateCell { cell: names.join(" ") });
+                    }
+                    *cell = Some(suffixes.clone());
+                }
+            }
+            Ok(())
+        }
+
+        fn into_table(self, name: String) -> Result<Table<C>, TableError> {
+            Table::from_cells(name, self.suffixes)
+        }
+    }
+
+    impl<C: InflectionalCategorySet<IndexType = usize>> Table<C> {
+        /// Read a table from TOML, checking it against the categories of `C`.
+        pub fn from_toml(source: &str) -> Result<Self, TableError> {
+            let file = toml::from_str(source).map_err(|error| TableError::Syntax { message: error.message().to_string() })?;
+            Self::from_file(file)
+        }
+
+        /// Read a table from JSON, checking it against the categories of `C`.
+        pub fn from_json(source: &str) -> Result<Self, TableError> {
+            let file = serde_json::from_str(source).map_err(|error| TableError::Syntax { message: error.to_string() })?;
+            Self::from_file(file)
+        }
+
+        fn from_file(file: TableFile) -> Result<Self, TableError> {
+            if file.categories != C::NAME {
+                return Err(TableError::WrongCategorySet { expected: C::NAME, found: file.categories });
+            }
+
+            let mut cells = Cells::<C>::new();
+            for (key, node) in file.cells {
+                cells.fill(&mut Vec::new(), &key, node)?;
+            }
+            cells.into_table(file.name)
+        }
+    }
+}
+
+/// The reasons a table may fail to load. Cells are named by their variants separated by spaces,
+/// e.g. "Feminine Singular Genitive".
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableError {
+    /// The file is not valid TOML or JSON, or is not laid out as a table.
+    Syntax { message: String },
+    /// The file is for another category set.
+    WrongCategorySet { expected: &'static str, found: String },
+    /// A name is not a variant of the category it stands for.
+    UnknownVariant { category: &'static str, name: String },
+    /// A cell is named by more variants than there are categories.
+    TooManyNames { cell: String },
+    /// Suffixes are given for a row rather than for a cell.
+    Incomplete { cell: String, category: &'static str },
+    DuplicateCell { cell: String },
+    MissingCell { cell: String },
+}
+
+impl std::fmt::Display for TableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TableError::Syntax { message } => write!(f, "the table is malformed: {}", message),
+            TableError::WrongCategorySet { expected, found } => {
+                write!(f, "the table is over {} but should be over {}", found, expected)
+            }
+            TableError::UnknownVariant { category, name } => write!(f, "\"{}\" is not a {}", name, category),
+            TableError::TooManyNames { cell } => write!(f, "\"{}\" names more categories than there are", cell),
+            TableError::Incomplete { cell, category } => {
+                write!(f, "\"{}\" is given suffixes but needs a {} as well", cell, category)
+            }
+            TableError::DuplicateCell { cell } => write!(f, "\"{}\" is given more than once", cell),
+            TableError::MissingCell { cell } => write!(f, "\"{}\" is missing", cell),
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latin::declension::*;
+
+    #[cfg(feature = "loader")]
+    const FIRST_DECLENSION_TOML: &str = include_str!("../tables/first_declension.toml");
+
+    #[test]
+    fn test_owned_words() {
+        let table = Arc::new(Table::from_suffix_inflection(THIRD_DECLENSION.name(), &THIRD_DECLENSION));
+        let words: Vec<OwnedWord<Arc<Table<NominalCategories>>>> = ["rēx, rēg", "dux, duc"]
+            .iter()
+            .map(|entry| {
+                let (lemma, root) = entry.split_once(", ").unwrap();
+                Word::builder(table.clone(), lemma.to_string(), root.to_string())
+                    .stem_change()
+                    .irregular(NominalCategories(Gender::Masculine, Number::Singular, Case::Vocative), Some(lemma.to_string().into()))
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+
+        assert_eq!(table.name(), "Third Declension");
+        assert_eq!(words[0].inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Genitive)).unwrap(), "rēgis");
+        assert_eq!(words[1].inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Vocative)).unwrap(), "dux");
+        assert_eq!(words[1].inflect(NominalCategories(Gender::Masculine, Number::Singular, Case::Nominative)).unwrap(), "dux");
+        for categories in NominalCategories::ALL {
+            assert_eq!(
+                (&*table).suffixes(categories).collect::<Vec<_>>(),
+                THIRD_DECLENSION.suffixes(categories).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "loader")]
+    fn test_from_toml() {
+        let table = Table::<NominalCategories>::from_toml(FIRST_DECLENSION_TOML).unwrap();
+
+        assert_eq!(table.name(), "First Declension");
+        for categories in NominalCategories::iter_through_variants() {
+            assert!((&table).suffixes(categories).eq(FIRST_DECLENSION.suffixes(categories)), "{:?}", categories);
+        }
+
+        let puella = Word::new(&table, "puella", "puell");
+        assert_eq!(puella.inflect(NominalCategories(Gender::Feminine, Number::Plural, Case::Genitive)).unwrap(), "puellārum");
+        assert_eq!(
+            puella.inflect(NominalCategories(Gender::Neuter, Number::Plural, Case::Genitive)),
+            Err(InflectionError::NotInInflection)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "loader")]
+    fn test_from_json() {
+        let json = r#"{
+            "name": "Third Declension Neuter",
+            "categories": "NominalCategories",
+            "cells": {
+                "Neuter Singular": {
+                    "Nominative": "(lemma)", "Genitive": "is", "Dative": "ī", "Accusative": "(lemma)",
+                    "Ablative": "e", "Vocative": "(lemma)", "Locative": null
+                },
+                "Neuter Plural": {
+                    "Nominative": "a", "Genitive": "um", "Dative": "ibus", "Accusative": "a",
+                    "Ablative": "ibus", "Vocative": "a", "Locative": []
+                },
+                "Masculine": null, "Feminine": null, "Common": null
+            }
+        }"#;
+        let table = Table::<NominalCategories>::from_json(json).unwrap();
+
+        let corpus = Word::builder(&table, "corpus", "corpor").stem_change().build().unwrap();
+        assert_eq!(corpus.inflect(NominalCategories(Gender::Neuter, Number::Singular, Case::Accusative)).unwrap(), "corpus");
+        assert_eq!(corpus.inflect(NominalCategories(Gender::Neuter, Number::Plural, Case::Dative)).unwrap(), "corporibus");
+    }
+
+    #[test]
+    #[cfg(feature = "loader")]
+    fn test_table_errors() {
+        let load = |cells: &str| {
+            Table::<NominalCategories>::from_toml(&format!("name = \"Test\"\ncategories = \"NominalCategories\"\n{}", cells))
+        };
+
+        assert_eq!(
+            load("[cells.Feminine.Singular]\nInstrumental = \"a\"").err(),
+            Some(TableError::UnknownVariant { category: "Case", name: "Instrumental".to_string() })
+        );
+        assert_eq!(
+            load("[cells]\nFemale = \"None\"").err(),
+            Some(TableError::UnknownVariant { category: "Gender", name: "Female".to_string() })
+        );
+        assert_eq!(
+            load("[cells]\n\"Singular Feminine\" = \"None\"").err(),
+            Some(TableError::UnknownVariant { category: "Gender", name: "Singular".to_string() })
+        );
+        assert_eq!(
+            load("[cells]\n\"Feminine Singular\" = \"a\"").err(),
+            Some(TableError::Incomplete { cell: "Feminine Singular".to_string(), category: "Case" })
+        );
+        assert_eq!(
+            load("[cells]\n\"Feminine Singular Nominative Nominative\" = \"a\"").err(),
+            Some(TableError::TooManyNames { cell: "Feminine Singular Nominative Nominative".to_string() })
+        );
+        assert_eq!(
+            load("[cells]\nFeminine = \"None\"\n\"Feminine Plural\" = \"None\"").err(),
+            Some(TableError::DuplicateCell { cell: "Feminine Plural Nominative".to_string() })
+        );
+        assert_eq!(
+            load("[cells]\nFeminine = \"None\"").err(),
+            Some(TableError::MissingCell { cell: "Masculine Singular Nominative".to_string() })
+        );
+        assert_eq!(
+            Table::<NominalCategories>::from_toml("name = \"Test\"\ncategories = \"VerbCategories\"\ncells = {}").err(),
+            Some(TableError::WrongCategorySet { expected: "NominalCategories", found: "VerbCategories".to_string() })
+        );
+        assert!(matches!(Table::<NominalCategories>::from_toml("name = \"Test\""), Err(TableError::Syntax { .. })));
+    }
+}